@@ -1,52 +1,172 @@
-use crate::app::ritz::get_ritz_movies_threaded;
-use crate::app::omd::Welcome;
-
-use chrono::{DateTime, Datelike, Local, TimeZone};
-use ratatui::widgets::ListState;
-use ratatui_image::{picker::Picker, protocol::StatefulProtocol};
-use std::collections::{HashMap, HashSet};
-use std::sync::mpsc;
+use crate::app::config::{
+    Config, DateOrder, ImageProtocol, PlotLength, format_showtime, load_config, normalize_api_key,
+    save_config_value,
+};
+use crate::app::diff::RefreshDiff;
+use crate::app::overview::OverviewColumn;
+use crate::app::cinema::{
+    CinemaSource, DayTiming, PartialScrape, ScrapeFailure, format_timings, get_day_threaded,
+    get_movies_threaded,
+};
+use crate::app::ritz::RitzCinema;
+use crate::app::usage::{UsageAction, UsageStats, load_usage, save_usage};
+use crate::app::upcoming::{MAX_WINDOW_HOURS, MIN_WINDOW_HOURS};
+use crate::app::export::{sessions_csv_detailed, sessions_json};
+use crate::app::favorites::{load_favorites, save_favorites};
+use crate::app::paths::clear_all_caches;
+use crate::app::fuzzy::fuzzy_score;
+use crate::app::state::{AppState, ListPosition, Reminder, load_state, save_state};
+use crate::app::ical::{CalendarSession, calendar, parse_runtime_minutes};
+use crate::app::utils::{
+    caching_available, get_cache_dir, get_export_dir, midnight_in, open_in_browser,
+    write_atomic,
+};
+use crate::app::net::{http_client, spawn_request};
+use crate::app::omd::{
+    LookupOptions, OmdbError, Welcome, check_api_key, may_be_remake, is_poster_cached, is_valid_poster_url, known_value, parse_imdb_id,
+    prefetch_poster,
+};
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Weekday};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::style::Print;
+use ratatui::layout::{Position, Rect};
+use ratatui::widgets::{ListState, TableState};
+use ratatui_image::{
+    picker::{Picker, ProtocolType, cap_parser::QueryStdioOptions},
+    protocol::StatefulProtocol,
+};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, mpsc};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 use std::fs;
+use std::io::{self, Write};
 use std::path::PathBuf;
 
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct CachedMovieData {
     pub movie_times: MovieTimes,
     pub last_updated: DateTime<Local>,
+    #[serde(default)]
+    pub sessions: SessionDetails,
+    #[serde(default)]
+    pub movie_pages: HashMap<String, String>,
+}
+
+/// An OMDb response kept on disk so reopening a movie doesn't spend another request
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CachedDetail {
+    pub details: Welcome,
+    pub fetched_at: DateTime<Local>,
+    /// Entries from before plots could be lengthened hold the short one
+    #[serde(default)]
+    pub plot: PlotLength,
+    /// Whether the lookup already looked for a recent film of the same name, which background
+    /// fetches skip to save requests
+    #[serde(default)]
+    pub recent_checked: bool,
+}
+
+/// Extra details scraped for an individual session
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SessionInfo {
+    pub booking_url: Option<String>,
+    /// Format tag such as "OC" or "3D", absent for standard sessions and older caches
+    #[serde(default)]
+    pub format: Option<String>,
+}
+
+/// Result of a showtime scrape: the bare times plus per-session details
+pub struct FetchedShowtimes {
+    pub movie_times: MovieTimes,
+    pub sessions: SessionDetails,
+    /// The label each date's page was fetched by, only meaningful on the day of the scrape
+    pub day_labels: HashMap<NaiveDate, String>,
+    /// Each movie's own page on the cinema site
+    pub movie_pages: HashMap<String, String>,
 }
 
 pub enum MovieFetchMessage {
     Progress(String),
-    Complete(MovieTimes),
-    Error(String),
+    DayProgress { done: usize, total: usize },
+    Timing(DayTiming),
+    Warning(String),
+    Complete(FetchedShowtimes),
+    /// One refreshed day's showtimes, replacing only that day's
+    DayComplete(NaiveDate, FetchedShowtimes),
+    Error(ScrapeFailure),
 }
 
+/// Detail and poster messages carry the `detail_generation` they were requested under,
+/// so results from a fetch that was superseded or backed out of are dropped
 pub enum MovieDetailMessage {
-    Complete(Welcome),
-    Error(String),
+    /// Details along with the scraped title they were requested for
+    Complete(u64, String, Box<Welcome>),
+    /// A request failed and is being retried
+    Retrying(u64, String),
+    /// What went wrong, plus the OMDb refusal when that was the cause
+    Error(u64, String, Option<OmdbError>),
 }
 
 pub enum PosterMessage {
-    Complete(StatefulProtocol),
-    Error(String),
+    Complete(u64, StatefulProtocol),
+    Error(u64, String),
+}
+
+/// The main screen region taking directional keys when `focus_regions` is on; the selected
+/// movie's times are a third region, tracked by `list_session_focus`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Focus {
+    Dates,
+    List,
+}
+
+/// Order of the date-filtered movie list
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListSort {
+    Alphabetical,
+    /// Soonest first session on the selected date first
+    EarliestShowtime,
+    /// Highest cached IMDb rating first, unrated movies last in alphabetical order
+    Rating,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CurrentScreen {
     Main,
-    Movie,
-    Date,
     MovieDetail,
+    RefreshDiff,
+    Heatmap,
+    Overview,
+    Upcoming,
+    DoubleFeature,
+    Reminders,
+    Stats,
+    /// The quit confirmation, drawn over `previous_screen`
     Exiting,
 }
 
 pub struct App {
     pub ritz_movie_times: MovieTimes,
     pub current_screen: CurrentScreen,
+    /// The screen to return to when the quit confirmation is declined
+    pub previous_screen: Option<CurrentScreen>,
     pub searching: bool,
     pub search_term: String,
+    /// The filter kept before the current search edit began, restored if the edit is discarded
+    committed_search: String,
     pub loading_movies: bool,
     pub loading_messages: Vec<String>,
+    /// Days fetched out of the days to fetch, unknown until the source has listed its days
+    pub loading_progress: Option<(usize, usize)>,
     pub receiver: Option<mpsc::Receiver<MovieFetchMessage>>,
+    /// Set while a fetch runs behind the movie list instead of on the loading screen
+    pub refreshing_in_background: bool,
+    /// Set to stop the running movie fetch; each fetch gets a fresh flag
+    fetch_cancel: Arc<AtomicBool>,
     pub selected_movie_index: usize,
     pub list_state: ListState,
     pub selected_date_index: usize,
@@ -54,32 +174,228 @@ pub struct App {
     pub last_updated: Option<DateTime<Local>>,
     pub selected_movie_detail: Option<Welcome>,
     pub loading_movie_detail: bool,
+    /// Set while the detail fetch is a forced refresh that skipped the detail cache
+    pub refreshing_detail: bool,
     pub movie_detail_error: Option<String>,
+    /// The OMDb refusal behind `movie_detail_error`, when it was one, so the remedy can match
+    pub omdb_error: Option<OmdbError>,
     pub omdb_api_key: Option<String>,
     pub detail_receiver: Option<mpsc::Receiver<MovieDetailMessage>>,
     pub poster_protocol: Option<StatefulProtocol>,
     pub loading_poster: bool,
+    /// Why the last poster download failed, shown in place of the poster
+    pub poster_error: Option<String>,
     pub poster_receiver: Option<mpsc::Receiver<PosterMessage>>,
+    /// Bumped whenever a detail fetch starts or the detail screen closes
+    pub detail_generation: u64,
     pub picker: Picker,
+    /// Which protocol draws posters and why, e.g. "kitty (auto-detected)", for debugging
+    pub picker_source: String,
+    pub refresh_diff: Option<RefreshDiff>,
+    pub config: Config,
+    pub franchise_group: Option<String>,
+    pub state: AppState,
+    pub detail_movie: Option<String>,
+    pub show_stale_banner: bool,
+    pub session_details: SessionDetails,
+    /// Each movie's own page on the cinema site, for booking from the list
+    pub movie_pages: HashMap<String, String>,
+    /// The label each date's page was last fetched by, with the day of that scrape, as
+    /// labels like "tomorrow" point elsewhere on any other day
+    day_labels: (NaiveDate, HashMap<NaiveDate, String>),
+    pub detail_session_index: usize,
+    pub status_message: Option<(String, Instant)>,
+    /// The soonest favorite's session starting within `favorite_alert_minutes`, shown in a
+    /// banner until a key is pressed or it starts
+    pub imminent_alert: Option<(String, DateTime<Local>)>,
+    /// Favorite sessions already alerted about, so each is only announced once
+    alerted_sessions: HashSet<(String, DateTime<Local>)>,
+    /// The last showtime fetch failure, shown in a banner above the list until a key is pressed
+    pub last_error: Option<(String, Instant)>,
+    pub benchmark: bool,
+    /// The cinema whose site is scraped for showtimes
+    pub cinema: Arc<dyn CinemaSource>,
+    /// Skip the delay between scrape requests (`--fast` or `CINEMA_TUI_FAST=1`)
+    pub fast_scrape: bool,
+    pub scrape_timings: Vec<DayTiming>,
+    pub compact_dates: bool,
+    /// When set, the main screen shows every date side by side instead of one date's list;
+    /// h/l then pick the column and j/k the movie within it
+    pub week_view: bool,
+    pub overview_state: TableState,
+    pub overview_sort: OverviewColumn,
+    pub overview_descending: bool,
+    /// False when the cache dir can't be written, so nothing persists between runs
+    pub caching_enabled: bool,
+    /// Set while asking whether to delete the caches
+    pub confirm_clear_cache: bool,
+    /// Weekday to select once showtimes arrive, from `--date` before they were loaded
+    pending_weekday: Option<Weekday>,
+    pub upcoming_window_hours: u32,
+    /// Logical lines of detail content scrolled past
+    pub detail_scroll: usize,
+    /// Rows the detail content had when last drawn, the distance PageUp/PageDown move
+    pub detail_page_height: usize,
+    pub detail_searching: bool,
+    pub detail_search: String,
+    /// When set, j/k on the detail screen move between sessions instead of scrolling
+    pub detail_session_focus: bool,
+    /// Typing an IMDb ID to correct the detail screen's OMDb match
+    pub correcting_match: bool,
+    pub match_input: String,
+    /// The movie whose note is being typed, if any
+    pub editing_note: Option<String>,
+    pub note_input: String,
+    /// Created on first copy and kept alive, as some platforms clear the clipboard when it's dropped
+    clipboard: Option<arboard::Clipboard>,
+    /// Local usage counters, only loaded when enabled in the config
+    usage: Option<UsageStats>,
+    /// OMDb details fetched this session, keyed by scraped movie name
+    pub detail_cache: HashMap<String, Welcome>,
+    /// Unexpired details from `detail_cache.json`, keyed by normalized title so they outlive
+    /// the current listings
+    stored_details: HashMap<String, CachedDetail>,
+    /// When the list selection last moved, cleared once a preload has been considered
+    selection_changed_at: Option<Instant>,
+    preload_receiver: Option<mpsc::Receiver<(String, Box<Welcome>)>>,
+    preloading: Option<String>,
+    /// Movies a rested selection still wants prefetched: itself, then its neighbours
+    prefetch_queue: VecDeque<String>,
+    /// Set while a prefetched poster downloads into the poster cache
+    poster_prefetch_receiver: Option<mpsc::Receiver<()>>,
+    /// Set while the startup key check runs; it only reports OMDb refusing the key
+    key_check_receiver: Option<mpsc::Receiver<OmdbError>>,
+    /// Background request threads that may still be running, so quitting can wait for them
+    workers: Vec<JoinHandle<()>>,
+    /// Movies whose background detail fetch failed, so the rating pass doesn't retry them
+    preload_failed: HashSet<String>,
+    pub list_sort: ListSort,
+    /// When false, cached dates before today are left out of date navigation
+    pub show_past_dates: bool,
+    /// When set, today's sessions that have already started are left out of the list
+    pub hide_past_showtimes: bool,
+    /// Starred movie titles, kept in their own file so they survive refreshes
+    pub favorites: HashSet<String>,
+    /// When set, the list shows only starred movies on every date
+    pub favorites_only: bool,
+    /// Lowest IMDb rating a movie can have and still be listed
+    pub min_rating: Option<f64>,
+    /// Days fetched before the last scrape failed, reused by the next refresh
+    pub partial_scrape: Option<PartialScrape>,
+    /// Problems noticed during the last scrape, shown in a banner until dismissed
+    pub scrape_warnings: Vec<String>,
+    /// When set, h/l in the list move between the selected movie's times instead of dates
+    pub list_session_focus: bool,
+    pub list_session_index: usize,
+    pub focus: Focus,
+    /// The list movie showing its week schedule and cached details inline, if any
+    pub expanded_movie: Option<String>,
+    /// When the app started, so animations step with monotonic time rather than redraws
+    animation_start: Instant,
+    /// Whether the popup for the focused list session is open
+    pub session_popup: bool,
+    pub reminder_state: ListState,
+    /// Whether the jump-to-movie palette is open
+    pub jump_palette: bool,
+    pub jump_input: String,
+    pub jump_index: usize,
+    /// Whether the genre filter popup is open, and its highlighted row; row 0 clears the filter
+    pub genre_popup: bool,
+    pub genre_index: usize,
+    /// When set, only movies whose cached details list this genre are shown
+    pub genre_filter: Option<String>,
+    /// Whether the keybinding help is drawn over the current screen, and its scroll in lines
+    pub show_help: bool,
+    pub help_scroll: usize,
+    /// Rows the help overlay had when last drawn, the distance PageUp/PageDown move
+    pub help_page_height: usize,
+    /// The times each date's list showed when last drawn, to spot changes on returning to it
+    date_snapshots: HashMap<NaiveDate, MovieTimes>,
+    /// Times that changed since the selected date was last drawn, highlighted for a moment
+    pub date_changes: Option<DateChanges>,
+    pub click_targets: ClickTargets,
+}
+
+/// Where the main screen's clickable parts were last drawn, for mapping mouse clicks
+#[derive(Debug, Default)]
+pub struct ClickTargets {
+    /// Inner area of the movie list, and each movie's height in rows in list order
+    pub list: Option<Rect>,
+    pub item_heights: Vec<u16>,
+    /// Each date shown in the date header, with its index into the visible dates
+    pub dates: Vec<(Rect, usize)>,
+    /// The date header when only the selected date fits; clicking a half steps the date
+    pub single_date: Option<Rect>,
+}
+
+/// Sessions added to or removed from a date since it was last on screen
+pub struct DateChanges {
+    pub date: NaiveDate,
+    pub added: HashSet<(String, DateTime<Local>)>,
+    pub removed: MovieTimes,
+    pub noticed_at: Instant,
 }
 
-type MovieTimes = HashMap<String, Vec<DateTime<Local>>>;
+/// How far each press moves the minimum rating filter
+const MIN_RATING_STEP: f64 = 0.5;
+
+/// How long the selection must rest on a movie before its details are preloaded
+const PRELOAD_DEBOUNCE: Duration = Duration::from_millis(400);
+
+/// How long changed times stay highlighted after returning to a date
+const DATE_CHANGE_HIGHLIGHT: Duration = Duration::from_secs(5);
+
+/// Most "similar movies" shown on the detail screen, each opened with its number key
+pub const SIMILAR_LIMIT: usize = 5;
+
+/// How long each animation frame, such as a spinner character, stays on screen
+const ANIMATION_FRAME: Duration = Duration::from_millis(100);
+
+/// How long footer status messages stay visible
+const STATUS_MESSAGE_DURATION: Duration = Duration::from_secs(4);
+
+/// How long the error banner stays up if no key is pressed
+const ERROR_BANNER_DURATION: Duration = Duration::from_secs(15);
+
+/// Longest quitting waits for background requests still running
+const SHUTDOWN_GRACE: Duration = Duration::from_millis(500);
+
+pub type MovieTimes = HashMap<String, Vec<DateTime<Local>>>;
+pub type SessionDetails = HashMap<String, HashMap<DateTime<Local>, SessionInfo>>;
 
 impl App {
     pub fn new() -> Self {
+        let (picker, picker_source) = image_picker(&load_config());
+        let mut app = Self::with_picker(picker);
+        app.picker_source = picker_source;
+        app
+    }
+
+    /// Builds the app around an existing picker, for callers that must not query the terminal
+    pub fn with_picker(picker: Picker) -> Self {
         let mut list_state = ListState::default();
         list_state.select(Some(0));
 
-        // Initialize picker for image rendering - query terminal or fallback to halfblocks
-        let picker = Picker::from_query_stdio().unwrap_or_else(|_| Picker::halfblocks());
+        let config = load_config();
+        let upcoming_window_hours = config
+            .upcoming_window_hours
+            .clamp(MIN_WINDOW_HOURS, MAX_WINDOW_HOURS);
+        // Centering on today only makes sense with the days before it in view
+        let show_past_dates =
+            config.show_past_dates || config.date_order == DateOrder::TodayCentered;
 
         let mut app = Self {
             ritz_movie_times: HashMap::new(),
             current_screen: CurrentScreen::Main,
+            previous_screen: None,
             searching: false,
             search_term: String::new(),
+            committed_search: String::new(),
             loading_movies: false,
             loading_messages: Vec::new(),
+            loading_progress: None,
+            refreshing_in_background: false,
+            fetch_cancel: Arc::new(AtomicBool::new(false)),
             receiver: None,
             selected_movie_index: 0,
             list_state,
@@ -88,50 +404,354 @@ impl App {
             last_updated: None,
             selected_movie_detail: None,
             loading_movie_detail: false,
+            refreshing_detail: false,
             movie_detail_error: None,
-            omdb_api_key: std::env::var("OMDB_API_KEY").ok(),
+            omdb_error: None,
+            // The environment wins, so a key can be tried without editing the config
+            omdb_api_key: normalize_api_key(std::env::var("OMDB_API_KEY").ok())
+                .or_else(|| normalize_api_key(config.omdb_api_key.clone())),
             detail_receiver: None,
             poster_protocol: None,
             loading_poster: false,
+            poster_error: None,
             poster_receiver: None,
+            detail_generation: 0,
+            picker_source: format!("{} (set by caller)", protocol_name(picker.protocol_type())),
             picker,
+            refresh_diff: None,
+            config,
+            franchise_group: None,
+            state: load_state(),
+            detail_movie: None,
+            show_stale_banner: false,
+            session_details: HashMap::new(),
+            movie_pages: HashMap::new(),
+            day_labels: (Local::now().date_naive(), HashMap::new()),
+            detail_session_index: 0,
+            status_message: None,
+            last_error: None,
+            imminent_alert: None,
+            alerted_sessions: HashSet::new(),
+            benchmark: std::env::var("CINEMA_TUI_BENCHMARK").is_ok_and(|value| value == "1"),
+            cinema: Arc::new(RitzCinema { use_cache: true }),
+            fast_scrape: std::env::var("CINEMA_TUI_FAST").is_ok_and(|value| value == "1"),
+            scrape_timings: Vec::new(),
+            compact_dates: false,
+            week_view: false,
+            overview_state: TableState::default().with_selected(Some(0)),
+            overview_sort: OverviewColumn::Movie,
+            overview_descending: false,
+            caching_enabled: caching_available(),
+            confirm_clear_cache: false,
+            pending_weekday: None,
+            upcoming_window_hours,
+            detail_scroll: 0,
+            detail_page_height: 0,
+            detail_searching: false,
+            detail_search: String::new(),
+            detail_session_focus: false,
+            correcting_match: false,
+            match_input: String::new(),
+            editing_note: None,
+            note_input: String::new(),
+            clipboard: None,
+            usage: None,
+            detail_cache: HashMap::new(),
+            stored_details: HashMap::new(),
+            selection_changed_at: Some(Instant::now()),
+            preload_receiver: None,
+            preloading: None,
+            prefetch_queue: VecDeque::new(),
+            poster_prefetch_receiver: None,
+            key_check_receiver: None,
+            workers: Vec::new(),
+            preload_failed: HashSet::new(),
+            list_sort: ListSort::Alphabetical,
+            show_past_dates,
+            hide_past_showtimes: false,
+            favorites: load_favorites(),
+            favorites_only: false,
+            min_rating: None,
+            partial_scrape: None,
+            scrape_warnings: Vec::new(),
+            list_session_focus: false,
+            list_session_index: 0,
+            focus: Focus::List,
+            expanded_movie: None,
+            animation_start: Instant::now(),
+            session_popup: false,
+            reminder_state: ListState::default().with_selected(Some(0)),
+            jump_palette: false,
+            jump_input: String::new(),
+            jump_index: 0,
+            genre_popup: false,
+            genre_index: 0,
+            genre_filter: None,
+            show_help: false,
+            help_scroll: 0,
+            help_page_height: 0,
+            date_snapshots: HashMap::new(),
+            date_changes: None,
+            click_targets: ClickTargets::default(),
         };
 
+        if app.config.usage_stats {
+            app.usage = Some(load_usage());
+        }
+
         // Try to load cached data
         app.load_cache();
+        app.load_detail_cache();
+        app.show_stale_banner = app.is_update_recommended();
+        if app.config.remember_list_position {
+            app.restore_list_position();
+        }
         app
     }
 
-    fn get_cache_path() -> PathBuf {
-        let mut path = dirs::cache_dir().unwrap_or_else(|| PathBuf::from("."));
-        path.push("cinema_tui");
-        fs::create_dir_all(&path).ok();
-        path.push("movie_cache.json");
-        path
+    pub fn get_cache_path() -> PathBuf {
+        get_cache_dir().join("movie_cache.json")
     }
 
     fn load_cache(&mut self) {
         let cache_path = Self::get_cache_path();
-        if let Ok(contents) = fs::read_to_string(&cache_path) {
-            if let Ok(cached_data) = serde_json::from_str::<CachedMovieData>(&contents) {
-                self.ritz_movie_times = cached_data.movie_times;
-                self.last_updated = Some(cached_data.last_updated);
-                self.update_available_dates();
+        // Writes are atomic, but a truncated file from an older version just fails to parse and is ignored
+        if let Ok(contents) = fs::read_to_string(&cache_path)
+            && let Ok(cached_data) = serde_json::from_str::<CachedMovieData>(&contents)
+        {
+            self.ritz_movie_times = cached_data.movie_times;
+            self.session_details = cached_data.sessions;
+            self.movie_pages = cached_data.movie_pages;
+            self.last_updated = Some(cached_data.last_updated);
+            self.prune_old_sessions();
+            self.remove_ignored_titles();
+            self.update_available_dates();
+        }
+    }
+
+    pub fn get_detail_cache_path() -> PathBuf {
+        get_cache_dir().join("detail_cache.json")
+    }
+
+    /// Loads unexpired OMDb details saved by earlier runs; an unreadable file means an empty cache
+    fn load_detail_cache(&mut self) {
+        let stored = fs::read_to_string(Self::get_detail_cache_path())
+            .ok()
+            .and_then(|contents| {
+                serde_json::from_str::<HashMap<String, CachedDetail>>(&contents).ok()
+            })
+            .unwrap_or_default();
+        let max_age = chrono::Duration::days(self.config.detail_cache_days as i64);
+        let now = Local::now();
+        self.stored_details = stored
+            .into_iter()
+            .filter(|(_, entry)| now.signed_duration_since(entry.fetched_at) < max_age)
+            .collect();
+        self.attach_stored_details();
+    }
+
+    /// Makes stored details available for listed movies that haven't been fetched this session
+    fn attach_stored_details(&mut self) {
+        for name in self.ritz_movie_times.keys() {
+            if !self.detail_cache.contains_key(name)
+                && let Some(entry) = self.stored_details.get(&detail_key(name))
+            {
+                self.detail_cache.insert(name.clone(), entry.details.clone());
             }
         }
     }
 
-    pub fn save_cache(&self) {
+    fn save_detail_cache(&self) {
+        if !self.caching_enabled {
+            return;
+        }
+        if let Ok(json) = serde_json::to_string_pretty(&self.stored_details) {
+            write_atomic(&Self::get_detail_cache_path(), json).ok();
+        }
+    }
+
+    /// Remembers fetched details for this session and, when caching, across runs
+    fn cache_detail(&mut self, name: String, details: Welcome, recent_checked: bool) {
+        self.stored_details.insert(
+            detail_key(&name),
+            CachedDetail {
+                details: details.clone(),
+                fetched_at: Local::now(),
+                plot: self.config.plot,
+                recent_checked,
+            },
+        );
+        self.detail_cache.insert(name, details);
+        self.save_detail_cache();
+    }
+
+    /// Drops a movie's details everywhere, so the next open fetches them again
+    fn forget_detail(&mut self, name: &str) {
+        self.detail_cache.remove(name);
+        if self.stored_details.remove(&detail_key(name)).is_some() {
+            self.save_detail_cache();
+        }
+    }
+
+    /// Drops listings matching the configured ignore patterns (private hire, promos, ...)
+    pub fn remove_ignored_titles(&mut self) {
+        let config = &self.config;
+        self.ritz_movie_times
+            .retain(|name, _| !config.is_ignored_title(name));
+        self.session_details
+            .retain(|name, _| !config.is_ignored_title(name));
+    }
+
+    /// Drops sessions older than `max_cache_days`, and movies left with none, so the cache stays bounded
+    pub fn prune_old_sessions(&mut self) {
+        let max_age = chrono::Days::new(self.config.max_cache_days as u64);
+        let cutoff = Local::now().date_naive() - max_age;
+
+        for times in self.ritz_movie_times.values_mut() {
+            times.retain(|time| time.date_naive() >= cutoff);
+        }
+        self.ritz_movie_times.retain(|_, times| !times.is_empty());
+
+        for sessions in self.session_details.values_mut() {
+            sessions.retain(|time, _| time.date_naive() >= cutoff);
+        }
+        self.session_details.retain(|_, sessions| !sessions.is_empty());
+        self.movie_pages
+            .retain(|name, _| self.ritz_movie_times.contains_key(name));
+    }
+
+    pub fn save_cache(&mut self) {
+        if !self.caching_enabled {
+            return;
+        }
+        self.prune_old_sessions();
         if let Some(last_updated) = self.last_updated {
             let cache_data = CachedMovieData {
                 movie_times: self.ritz_movie_times.clone(),
                 last_updated,
+                sessions: self.session_details.clone(),
+                movie_pages: self.movie_pages.clone(),
             };
             
             if let Ok(json) = serde_json::to_string_pretty(&cache_data) {
                 let cache_path = Self::get_cache_path();
-                fs::write(cache_path, json).ok();
+                write_atomic(&cache_path, json).ok();
+            }
+        }
+    }
+
+    /// Replaces the loaded showtimes with a fresh scrape, noting what changed and saving the cache
+    pub fn apply_fetched_showtimes(&mut self, fetched: FetchedShowtimes) {
+        // A refresh keeps the date and movie in view when they are still listed
+        let selected_date = self.get_selected_date().copied();
+        let selected_movie = self.get_selected_movie_name();
+        // Keep the pre-refresh snapshot around to summarise what changed
+        let previous = std::mem::replace(&mut self.ritz_movie_times, fetched.movie_times);
+        self.session_details = fetched.sessions;
+        self.movie_pages = fetched.movie_pages;
+        self.day_labels = (Local::now().date_naive(), fetched.day_labels);
+        self.remove_ignored_titles();
+        self.refresh_diff = if previous.is_empty() {
+            None
+        } else {
+            Some(RefreshDiff::between(&previous, &self.ritz_movie_times))
+        };
+        self.last_updated = Some(Local::now());
+        self.update_available_dates();
+        self.attach_stored_details();
+        self.save_cache();
+        if let Some(index) = selected_date
+            .and_then(|date| self.visible_dates().iter().position(|d| *d == date))
+        {
+            self.selected_date_index = index;
+        }
+        self.reselect_after_date_change(selected_movie);
+        self.session_popup = false;
+        if let Some(weekday) = self.pending_weekday.take() {
+            self.select_weekday(weekday);
+        }
+    }
+
+    /// Forgets the showtimes and details loaded from the caches and stops writing them back,
+    /// for `--no-cache`
+    pub fn disable_cache(&mut self) {
+        self.caching_enabled = false;
+        self.cinema = Arc::new(RitzCinema { use_cache: false });
+        self.ritz_movie_times.clear();
+        self.session_details.clear();
+        self.movie_pages.clear();
+        self.last_updated = None;
+        self.detail_cache.clear();
+        self.stored_details.clear();
+        self.update_available_dates();
+        self.show_stale_banner = false;
+    }
+
+    /// Deletes every cache file and favorites, then starts over as on a first run
+    pub fn clear_caches(&mut self) {
+        self.confirm_clear_cache = false;
+        let removed = match clear_all_caches() {
+            Ok(removed) => removed,
+            Err(e) => {
+                self.set_status(&format!("Couldn't clear the cache: {}", e));
+                return;
+            }
+        };
+
+        self.ritz_movie_times.clear();
+        self.session_details.clear();
+        self.movie_pages.clear();
+        self.last_updated = None;
+        self.detail_cache.clear();
+        self.stored_details.clear();
+        self.favorites.clear();
+        self.favorites_only = false;
+        self.refresh_diff = None;
+        self.partial_scrape = None;
+        self.date_snapshots.clear();
+        self.date_changes = None;
+        self.update_available_dates();
+        self.selected_movie_index = 0;
+        self.list_state.select(Some(0));
+        self.list_session_index = 0;
+        self.show_stale_banner = false;
+
+        if removed.is_empty() {
+            self.set_status("Cache was already clear");
+        } else {
+            self.set_status(&format!("Cleared: {}", removed.join(", ")));
+        }
+    }
+
+    /// Selects the first visible date on `weekday`, or waits for showtimes if none are loaded
+    pub fn select_weekday(&mut self, weekday: Weekday) {
+        if self.available_dates.is_empty() || self.is_fetching_movies() {
+            self.pending_weekday = Some(weekday);
+            return;
+        }
+        match self
+            .visible_dates()
+            .iter()
+            .position(|date| date.weekday() == weekday)
+        {
+            Some(index) => self.select_date(index),
+            None => self.set_status(&format!("No showtimes on a {} to show", weekday)),
+        }
+    }
+
+    /// Selects today's date if it has showtimes, returning whether it was found
+    pub fn select_today(&mut self) -> bool {
+        let today = self.config.today();
+        match self
+            .visible_dates()
+            .iter()
+            .position(|date| date.date_naive() == today)
+        {
+            Some(index) => {
+                self.selected_date_index = index;
+                true
             }
+            None => false,
         }
     }
 
@@ -139,8 +759,9 @@ impl App {
         match self.last_updated {
             Some(last_updated) => {
                 let now = Local::now();
-                let duration = now.signed_duration_since(last_updated);
-                
+                // A clock set backwards (e.g. an NTP correction) would otherwise give a negative age
+                let duration = now.signed_duration_since(last_updated).max(chrono::Duration::zero());
+
                 if duration.num_minutes() < 1 {
                     "Just now".to_string()
                 } else if duration.num_minutes() < 60 {
@@ -176,15 +797,261 @@ impl App {
         false
     }
 
+    /// Milliseconds to pause between day page requests; none at all in fast mode
+    pub fn scrape_delay(&self) -> RangeInclusive<u64> {
+        if self.fast_scrape {
+            0..=0
+        } else {
+            self.config.scrape_delay()
+        }
+    }
+
+    /// True while a showtime fetch is running or its results haven't been applied yet
+    pub fn is_fetching_movies(&self) -> bool {
+        self.loading_movies || self.receiver.is_some()
+    }
+
     pub fn fetch_movies(&mut self) {
-        let (sender, receiver) = mpsc::channel();
-        self.receiver = Some(receiver);
+        if self.is_fetching_movies() {
+            return;
+        }
+
+        self.track(UsageAction::Refresh);
+        let sender = replace_channel(&mut self.receiver);
+        self.show_stale_banner = false;
         self.loading_movies = true;
         self.loading_messages.clear();
+        self.loading_progress = None;
+        self.scrape_timings.clear();
+        self.scrape_warnings.clear();
 
-        std::thread::spawn(move || {
-            get_ritz_movies_threaded(sender);
-        });
+        // Pick up where a failed refresh left off, as long as it was earlier today
+        let resume = self
+            .partial_scrape
+            .take()
+            .filter(|partial| partial.is_current())
+            .unwrap_or_default();
+        if !resume.labels.is_empty() {
+            self.loading_messages.push(format!(
+                "Retrying - keeping {} day(s) fetched before the last error",
+                resume.labels.len()
+            ));
+        }
+
+        self.fetch_cancel = Arc::new(AtomicBool::new(false));
+        let cancel = Arc::clone(&self.fetch_cancel);
+        let cinema = Arc::clone(&self.cinema);
+        let min_days = self.config.min_scrape_days;
+        let extra_days = self.config.scrape_extra_days();
+        let delay = self.scrape_delay();
+        let worker = spawn_request(
+            sender,
+            |error| {
+                Some(MovieFetchMessage::Error(ScrapeFailure {
+                    error,
+                    partial: Box::default(),
+                }))
+            },
+            move |sender| {
+                get_movies_threaded(
+                    cinema.as_ref(),
+                    sender.clone(),
+                    min_days,
+                    extra_days,
+                    delay,
+                    &cancel,
+                    resume,
+                );
+            },
+        );
+        self.keep_worker(worker);
+    }
+
+    /// Re-scrapes only the selected date in the background, leaving the list on screen and
+    /// the other days' showtimes untouched
+    pub fn refresh_selected_date(&mut self) {
+        if self.is_fetching_movies() {
+            return;
+        }
+        let Some(date) = self.get_selected_date().map(|date| date.date_naive()) else {
+            self.set_status("No date selected to refresh");
+            return;
+        };
+
+        self.track(UsageAction::Refresh);
+        let sender = replace_channel(&mut self.receiver);
+        self.refreshing_in_background = true;
+        self.loading_messages.clear();
+        self.loading_progress = None;
+        self.scrape_timings.clear();
+        self.set_status(&format!(
+            "Refreshing {}...",
+            date.format(self.config.date_format.short())
+        ));
+
+        self.fetch_cancel = Arc::new(AtomicBool::new(false));
+        let cancel = Arc::clone(&self.fetch_cancel);
+        let cinema = Arc::clone(&self.cinema);
+        let min_days = self.config.min_scrape_days;
+        let extra_days = self.config.scrape_extra_days();
+        let delay = self.scrape_delay();
+        let worker = spawn_request(
+            sender,
+            |error| {
+                Some(MovieFetchMessage::Error(ScrapeFailure {
+                    error,
+                    partial: Box::default(),
+                }))
+            },
+            move |sender| {
+                get_day_threaded(
+                    cinema.as_ref(),
+                    sender.clone(),
+                    date,
+                    min_days,
+                    extra_days,
+                    delay,
+                    &cancel,
+                );
+            },
+        );
+        self.keep_worker(worker);
+    }
+
+    /// Swaps one date's showtimes for freshly scraped ones, keeping every other date as it was
+    pub fn apply_fetched_day(&mut self, date: NaiveDate, fetched: FetchedShowtimes) {
+        let previous = self.ritz_movie_times.clone();
+        let selected = self.get_selected_movie_name();
+
+        for (name, times) in self.ritz_movie_times.iter_mut() {
+            let sessions = self.session_details.get_mut(name);
+            let mut removed = Vec::new();
+            times.retain(|time| {
+                let keep = self.config.movie_night(*time) != date;
+                if !keep {
+                    removed.push(*time);
+                }
+                keep
+            });
+            if let Some(sessions) = sessions {
+                for time in removed {
+                    sessions.remove(&time);
+                }
+            }
+        }
+        for (name, times) in fetched.movie_times {
+            let existing = self.ritz_movie_times.entry(name).or_default();
+            for time in times {
+                if !existing.contains(&time) {
+                    existing.push(time);
+                }
+            }
+            existing.sort();
+        }
+        for (name, sessions) in fetched.sessions {
+            self.session_details.entry(name).or_default().extend(sessions);
+        }
+        self.movie_pages.extend(fetched.movie_pages);
+        let today = Local::now().date_naive();
+        if self.day_labels.0 != today {
+            self.day_labels = (today, HashMap::new());
+        }
+        self.day_labels.1.extend(fetched.day_labels);
+        self.ritz_movie_times.retain(|_, times| !times.is_empty());
+        self.session_details
+            .retain(|name, _| self.ritz_movie_times.contains_key(name));
+
+        self.remove_ignored_titles();
+        self.refresh_diff = Some(RefreshDiff::between(&previous, &self.ritz_movie_times));
+        self.update_available_dates();
+        // Rebuilding the dates resets the selection, so go back to the refreshed one
+        if let Some(index) = self
+            .visible_dates()
+            .iter()
+            .position(|visible| visible.date_naive() == date)
+        {
+            self.selected_date_index = index;
+        }
+        self.attach_stored_details();
+        self.save_cache();
+        self.select_movie_by_name(&selected.unwrap_or_default());
+        self.list_session_index = 0;
+        self.set_status(&format!(
+            "Refreshed {}",
+            date.format(self.config.date_format.short())
+        ));
+    }
+
+    /// With `auto_refresh_on_stale` set, refreshes cached showtimes that are out of date or
+    /// older than `auto_refresh_after_hours`, leaving them on screen until the new ones land.
+    /// A first run without a cache is left to the user, as the fetch would show nothing anyway.
+    /// Whether no loaded session reaches the last week the `weeks` setting asks for
+    pub fn is_short_of_weeks(&self) -> bool {
+        let weeks_ahead = self.config.weeks.saturating_sub(1) as u64;
+        let last_week = self.config.today() + chrono::Days::new(weeks_ahead * 7);
+        self.ritz_movie_times
+            .values()
+            .flatten()
+            .all(|time| self.config.movie_night(*time) < last_week)
+    }
+
+    pub fn auto_refresh_if_stale(&mut self) {
+        if !self.config.auto_refresh_on_stale || self.ritz_movie_times.is_empty() {
+            return;
+        }
+        let max_age = chrono::Duration::hours(self.config.auto_refresh_after_hours as i64);
+        let too_old = self
+            .last_updated
+            .is_some_and(|updated| Local::now() - updated > max_age);
+        if !self.is_update_recommended() && !too_old {
+            return;
+        }
+
+        self.fetch_movies();
+        self.loading_movies = false;
+        self.refreshing_in_background = true;
+    }
+
+    /// Remembers a background request thread, forgetting those that have finished
+    fn keep_worker(&mut self, worker: JoinHandle<()>) {
+        self.workers.retain(|worker| !worker.is_finished());
+        self.workers.push(worker);
+    }
+
+    /// Winds down background work on quit: the scrape stops before its next day, every result
+    /// channel is dropped so nothing more is delivered, and running threads get up to
+    /// `SHUTDOWN_GRACE` to finish what they are writing. Any still blocked on the network
+    /// after that end with the process.
+    pub fn shutdown(&mut self) {
+        self.fetch_cancel.store(true, Ordering::Relaxed);
+        self.receiver = None;
+        self.detail_receiver = None;
+        self.poster_receiver = None;
+        self.preload_receiver = None;
+        self.poster_prefetch_receiver = None;
+        self.key_check_receiver = None;
+
+        let deadline = Instant::now() + SHUTDOWN_GRACE;
+        while self.workers.iter().any(|worker| !worker.is_finished()) && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        for worker in self.workers.drain(..).filter(|worker| worker.is_finished()) {
+            let _ = worker.join();
+        }
+    }
+
+    /// Stops the running movie fetch and goes back to the showtimes from before it started.
+    /// The scrape thread finishes the requests already in flight and exits quietly.
+    pub fn cancel_fetch(&mut self) {
+        if !self.loading_movies {
+            return;
+        }
+        self.fetch_cancel.store(true, Ordering::Relaxed);
+        self.receiver = None;
+        self.loading_movies = false;
+        self.loading_messages.clear();
+        self.loading_progress = None;
+        self.set_status("Refresh cancelled");
     }
 
     pub fn next_movie(&mut self) {
@@ -193,8 +1060,14 @@ impl App {
             return;
         }
 
-        self.selected_movie_index = (self.selected_movie_index + 1) % movie_count;
+        self.selected_movie_index = if self.config.wrap_navigation {
+            (self.selected_movie_index + 1) % movie_count
+        } else {
+            (self.selected_movie_index + 1).min(movie_count - 1)
+        };
         self.list_state.select(Some(self.selected_movie_index));
+        self.list_session_index = 0;
+        self.selection_changed_at = Some(Instant::now());
     }
 
     pub fn previous_movie(&mut self) {
@@ -203,92 +1076,291 @@ impl App {
             return;
         }
 
-        if self.selected_movie_index == 0 {
+        if self.selected_movie_index == 0 && self.config.wrap_navigation {
             self.selected_movie_index = movie_count - 1;
         } else {
             self.selected_movie_index = self.selected_movie_index.saturating_sub(1);
         }
         self.list_state.select(Some(self.selected_movie_index));
+        self.list_session_index = 0;
+        self.selection_changed_at = Some(Instant::now());
     }
 
-    pub fn get_sorted_movies(&self) -> Vec<(String, Vec<chrono::DateTime<chrono::Local>>)> {
-        let mut movies: Vec<_> = self
-            .ritz_movie_times
-            .iter()
-            .map(|(name, times)| (name.clone(), times.clone()))
-            .collect();
-        movies.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
-        movies
+    pub fn next_overview_row(&mut self) {
+        let row_count = self.ritz_movie_times.len();
+        if row_count == 0 {
+            return;
+        }
+
+        let next = self.overview_state.selected().map_or(0, |i| (i + 1) % row_count);
+        self.overview_state.select(Some(next));
+    }
+
+    pub fn previous_overview_row(&mut self) {
+        let row_count = self.ritz_movie_times.len();
+        if row_count == 0 {
+            return;
+        }
+
+        let previous = match self.overview_state.selected() {
+            Some(0) | None => row_count - 1,
+            Some(i) => i - 1,
+        };
+        self.overview_state.select(Some(previous));
+    }
+
+    /// Sorts the overview table by the next column, ascending
+    pub fn cycle_overview_sort(&mut self) {
+        self.overview_sort = self.overview_sort.next();
+        self.overview_descending = false;
+        self.overview_state.select(Some(0));
+    }
+
+    pub fn reverse_overview_sort(&mut self) {
+        self.overview_descending = !self.overview_descending;
+        self.overview_state.select(Some(0));
+    }
+
+    /// Widens (positive) or narrows (negative) the "starting soon" window by `delta` hours
+    pub fn adjust_upcoming_window(&mut self, delta: i32) {
+        self.upcoming_window_hours = self
+            .upcoming_window_hours
+            .saturating_add_signed(delta)
+            .clamp(MIN_WINDOW_HOURS, MAX_WINDOW_HOURS);
     }
 
     pub fn update_available_dates(&mut self) {
-        let mut dates = HashSet::new();
+        self.available_dates = showtime_dates(&self.ritz_movie_times, &self.config);
+        self.select_current_date();
+    }
 
-        for times in self.ritz_movie_times.values() {
-            for time in times {
-                // Get date at midnight for comparison
-                let date = time.date_naive().and_hms_opt(0, 0, 0).unwrap();
-                let date_time = Local.from_local_datetime(&date).unwrap();
-                dates.insert(date_time.timestamp());
-            }
+    /// Selects today, or the next date with showtimes when today has none. When every date
+    /// is past, the latest is selected.
+    fn select_current_date(&mut self) {
+        let today = self.config.today();
+        let dates = self.visible_dates();
+        self.selected_date_index = dates
+            .partition_point(|date| date.date_naive() < today)
+            .min(dates.len().saturating_sub(1));
+    }
+
+    /// The navigable dates: all cached dates, or only today onwards while past dates are hidden
+    pub fn visible_dates(&self) -> &[DateTime<Local>] {
+        if self.show_past_dates {
+            return &self.available_dates;
         }
+        // Dates are sorted, so the past ones form a prefix
+        let today = self.config.today();
+        let first_current = self
+            .available_dates
+            .partition_point(|date| date.date_naive() < today);
+        &self.available_dates[first_current..]
+    }
 
-        self.available_dates = dates
-            .into_iter()
-            .map(|timestamp| {
-                DateTime::from_timestamp(timestamp, 0)
-                    .unwrap()
-                    .with_timezone(&Local)
-            })
-            .collect();
+    /// Shows or hides past dates, keeping the selected date when it is still visible
+    pub fn toggle_past_dates(&mut self) {
+        let selected = self.get_selected_date().copied();
+        let movie = self.get_selected_movie_name();
+        self.show_past_dates = !self.show_past_dates;
+        self.selected_date_index = selected
+            .and_then(|date| self.visible_dates().iter().position(|d| *d == date))
+            .unwrap_or(0);
+        self.reselect_after_date_change(movie);
+    }
+
+    /// Shows or hides today's sessions that have already started, keeping the selected movie
+    /// when it still has sessions to come
+    pub fn toggle_past_showtimes(&mut self) {
+        let selected = self.get_selected_movie_name();
+        self.hide_past_showtimes = !self.hide_past_showtimes;
+        if let Some(name) = selected {
+            self.select_movie_by_name(&name);
+        }
+        self.list_session_index = 0;
+    }
+
+    /// Stars the selected movie, or unstars it if it already is
+    pub fn toggle_favorite(&mut self) {
+        let Some(name) = self.get_selected_movie_name() else {
+            return;
+        };
+        if self.favorites.remove(&name) {
+            self.set_status(&format!("Removed {} from favorites", name));
+        } else {
+            self.set_status(&format!("Added {} to favorites", name));
+            self.favorites.insert(name.clone());
+        }
+        save_favorites(&self.favorites);
+        // Unstarring can drop the movie from a favorites-only list
+        self.select_movie_by_name(&name);
+    }
+
+    pub fn toggle_favorites_only(&mut self) {
+        let selected = self.get_selected_movie_name();
+        self.favorites_only = !self.favorites_only;
+        self.select_movie_by_name(&selected.unwrap_or_default());
+        self.list_session_index = 0;
+    }
+
+    /// Raises or lowers the minimum rating by `steps` half points, clearing it once it drops
+    /// below the lowest step
+    pub fn adjust_min_rating(&mut self, steps: i32) {
+        let selected = self.get_selected_movie_name();
+        let rating = self.min_rating.unwrap_or(0.0) + f64::from(steps) * MIN_RATING_STEP;
+        self.min_rating = (rating >= MIN_RATING_STEP).then_some(rating.min(10.0));
+        self.select_movie_by_name(&selected.unwrap_or_default());
+        self.list_session_index = 0;
+    }
 
-        self.available_dates.sort();
+    pub fn clear_min_rating(&mut self) {
+        let selected = self.get_selected_movie_name();
+        self.min_rating = None;
+        self.select_movie_by_name(&selected.unwrap_or_default());
+        self.list_session_index = 0;
+    }
 
-        // Reset to first date
-        self.selected_date_index = 0;
+    /// Whether a movie passes the minimum rating; unknown ratings pass unless configured not to
+    fn meets_min_rating(&self, name: &str) -> bool {
+        let Some(min_rating) = self.min_rating else {
+            return true;
+        };
+        match self.detail_cache.get(name).and_then(|details| parse_rating(&details.imdb_rating)) {
+            Some(rating) => rating >= min_rating,
+            None => !self.config.min_rating_hides_unrated,
+        }
     }
 
     pub fn next_date(&mut self) {
-        if !self.available_dates.is_empty() {
-            self.selected_date_index = (self.selected_date_index + 1) % self.available_dates.len();
-            self.selected_movie_index = 0;
-            self.list_state.select(Some(0));
+        let date_count = self.visible_dates().len();
+        let wraps = self.config.date_order == DateOrder::Ascending && self.config.wrap_navigation;
+        // Stopping at the last date leaves the movie selection where it was
+        if date_count > 0 && (wraps || self.selected_date_index + 1 < date_count) {
+            let movie = self.get_selected_movie_name();
+            self.selected_date_index = (self.selected_date_index + 1) % date_count;
+            self.reselect_after_date_change(movie);
         }
     }
 
     pub fn previous_date(&mut self) {
-        if !self.available_dates.is_empty() {
+        let date_count = self.visible_dates().len();
+        let wraps = self.config.date_order == DateOrder::Ascending && self.config.wrap_navigation;
+        if date_count > 0 && (wraps || self.selected_date_index > 0) {
+            let movie = self.get_selected_movie_name();
             if self.selected_date_index == 0 {
-                self.selected_date_index = self.available_dates.len() - 1;
+                self.selected_date_index = date_count - 1;
             } else {
-                self.selected_date_index = self.selected_date_index.saturating_sub(1);
+                self.selected_date_index -= 1;
             }
-            self.selected_movie_index = 0;
-            self.list_state.select(Some(0));
+            self.reselect_after_date_change(movie);
         }
     }
 
-    pub fn get_selected_date(&self) -> Option<&DateTime<Local>> {
-        self.available_dates.get(self.selected_date_index)
+    /// Selects the visible date at `index`, ignoring indexes past the last date
+    pub fn select_date(&mut self, index: usize) {
+        if index == self.selected_date_index || index >= self.visible_dates().len() {
+            return;
+        }
+        let movie = self.get_selected_movie_name();
+        self.selected_date_index = index;
+        self.reselect_after_date_change(movie);
     }
 
-    pub fn get_filtered_movies(&self) -> Vec<(String, Vec<chrono::DateTime<chrono::Local>>)> {
-        let selected_date = match self.get_selected_date() {
-            Some(date) => date,
-            None => return Vec::new(),
+    /// Keeps `movie` selected after a date change when it shows on the new date too, and
+    /// otherwise starts from the top of the list
+    fn reselect_after_date_change(&mut self, movie: Option<String>) {
+        match movie {
+            Some(name) => self.select_movie_by_name(&name),
+            None => {
+                self.selected_movie_index = 0;
+                self.list_state.select(Some(0));
+            }
+        }
+        self.list_session_index = 0;
+        self.selection_changed_at = Some(Instant::now());
+    }
+
+    /// Selects today's date, or says so when the schedule doesn't include it
+    pub fn jump_to_today(&mut self) {
+        let today = self.config.today();
+        match self
+            .visible_dates()
+            .iter()
+            .position(|date| date.date_naive() == today)
+        {
+            Some(index) => self.select_date(index),
+            None => self.set_status("No showtimes today"),
+        }
+    }
+
+    /// Handles a left click on the main screen: a date in the header selects it, a movie
+    /// selects it, and a click on the already selected movie opens its details
+    pub fn click(&mut self, column: u16, row: u16) {
+        let position = Position::new(column, row);
+
+        if let Some(&(_, index)) =
+            self.click_targets.dates.iter().find(|(area, _)| area.contains(position))
+        {
+            self.select_date(index);
+            return;
+        }
+        if let Some(area) = self.click_targets.single_date
+            && area.contains(position)
+        {
+            if column < area.x + area.width / 2 {
+                self.previous_date();
+            } else {
+                self.next_date();
+            }
+            return;
+        }
+
+        let Some(index) = self.movie_at(position) else {
+            return;
         };
+        if index == self.selected_movie_index {
+            if let Some(movie_name) = self.get_selected_movie_name() {
+                self.open_movie_detail(movie_name);
+            }
+            return;
+        }
+        self.selected_movie_index = index;
+        self.list_state.select(Some(index));
+        self.list_session_index = 0;
+        self.list_session_focus = false;
+        self.selection_changed_at = Some(Instant::now());
+    }
 
-        let mut movies: Vec<_> = self
-            .ritz_movie_times
+    /// The list movie drawn at `position`, walking item heights from the scroll offset
+    fn movie_at(&self, position: Position) -> Option<usize> {
+        let list = self.click_targets.list.filter(|list| list.contains(position))?;
+        let mut top = list.y;
+        for (index, height) in self
+            .click_targets
+            .item_heights
+            .iter()
+            .enumerate()
+            .skip(self.list_state.offset())
+        {
+            if position.y < top + height {
+                return Some(index);
+            }
+            top += height;
+        }
+        None
+    }
+
+    pub fn get_selected_date(&self) -> Option<&DateTime<Local>> {
+        self.visible_dates().get(self.selected_date_index)
+    }
+
+    /// Every movie's times on `date`, before the search and upcoming-only filters
+    fn movie_times_on(&self, date: NaiveDate) -> MovieTimes {
+        self.ritz_movie_times
             .iter()
             .filter_map(|(name, times)| {
                 let filtered_times: Vec<DateTime<Local>> = times
                     .iter()
-                    .filter(|time| {
-                        time.year() == selected_date.year()
-                            && time.month() == selected_date.month()
-                            && time.day() == selected_date.day()
-                    })
+                    .filter(|time| self.config.movie_night(**time) == date)
                     .copied()
                     .collect();
 
@@ -298,62 +1370,1923 @@ impl App {
                     Some((name.clone(), filtered_times))
                 }
             })
-            .collect();
-
-        movies.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
-        movies
-    }
-
-    pub fn get_selected_movie_name(&self) -> Option<String> {
-        let movies = self.get_filtered_movies();
-        movies.get(self.selected_movie_index).map(|(name, _)| name.clone())
+            .collect()
     }
 
-    pub fn fetch_movie_detail(&mut self, movie_name: String) {
-        if self.omdb_api_key.is_none() {
-            self.movie_detail_error = Some("API key not set".to_string());
-            self.loading_movie_detail = false;
-            return;
+    pub fn get_filtered_movies(&self) -> Vec<(String, Vec<chrono::DateTime<chrono::Local>>)> {
+        match self.get_selected_date() {
+            Some(date) => self.filtered_movies_on(date.date_naive()),
+            None => Vec::new(),
         }
+    }
 
-        let (sender, receiver) = mpsc::channel();
-        self.detail_receiver = Some(receiver);
-        self.loading_movie_detail = true;
-        self.selected_movie_detail = None;
-        self.movie_detail_error = None;
-
-        let api_key = self.omdb_api_key.clone().unwrap();
+    /// The list for any date, with the same filters and order the list applies to the selected one
+    pub fn filtered_movies_on(&self, selected_date: NaiveDate) -> Vec<(String, Vec<DateTime<Local>>)> {
+        let search = self.search_term.to_lowercase();
+        // Only today's sessions can have passed; later dates are unaffected by the toggle
+        let hide_before = (self.hide_past_showtimes && selected_date == self.config.today())
+            .then(Local::now);
 
-        std::thread::spawn(move || {
-            match crate::app::omd::fetch_movie_details(&movie_name, &api_key) {
+        let mut movies: Vec<_> = self
+            .movie_times_on(selected_date)
+            .into_iter()
+            .filter(|(name, _)| search.is_empty() || name.to_lowercase().contains(&search))
+            .filter(|(name, _)| !self.favorites_only || self.favorites.contains(name))
+            .filter(|(name, _)| self.meets_min_rating(name))
+            // Movies without cached details have no genre to match, so a filter hides them
+            .filter(|(name, _)| {
+                self.genre_filter
+                    .as_ref()
+                    .is_none_or(|genre| self.movie_genres(name).any(|g| g == genre))
+            })
+            .filter_map(|(name, mut times)| {
+                if let Some(now) = hide_before {
+                    times.retain(|time| *time >= now);
+                }
+                (!times.is_empty()).then_some((name, times))
+            })
+            .collect();
+
+        movies.sort_by_key(|(name, _)| name.to_lowercase());
+
+        // Stable sorts, so ties and movies without a rating yet stay alphabetical
+        match self.list_sort {
+            ListSort::Alphabetical => {}
+            ListSort::EarliestShowtime => {
+                movies.sort_by_key(|(_, times)| times.iter().min().copied());
+            }
+            ListSort::Rating => {
+                movies.sort_by(|(a, _), (b, _)| {
+                    let rating = |name: &String| self.cached_rating(name);
+                    rating(b).total_cmp(&rating(a))
+                });
+            }
+        }
+
+        // Stable partition so the grouped franchise leads, alphabetical within each group
+        if let Some(prefix) = &self.franchise_group {
+            movies.sort_by_key(|(name, _)| franchise_key(name).as_deref() != Some(prefix.as_str()));
+        }
+
+        movies
+    }
+
+    /// The genres in a movie's cached details, which OMDb lists comma-separated
+    fn movie_genres(&self, name: &str) -> impl Iterator<Item = &str> {
+        self.detail_cache
+            .get(name)
+            .and_then(|details| known_value(&details.genre))
+            .into_iter()
+            .flat_map(|genres| genres.split(','))
+            .map(str::trim)
+            .filter(|genre| !genre.is_empty())
+    }
+
+    /// Every genre among the cached details of scheduled movies, alphabetically
+    pub fn available_genres(&self) -> Vec<String> {
+        let genres: BTreeSet<&str> = self
+            .ritz_movie_times
+            .keys()
+            .flat_map(|name| self.movie_genres(name))
+            .collect();
+        genres.into_iter().map(str::to_string).collect()
+    }
+
+    /// Opens the genre popup with the active filter highlighted
+    pub fn open_genre_popup(&mut self) {
+        let genres = self.available_genres();
+        self.genre_index = self
+            .genre_filter
+            .as_ref()
+            .and_then(|active| genres.iter().position(|genre| genre == active))
+            .map_or(0, |index| index + 1);
+        self.genre_popup = true;
+    }
+
+    pub fn next_genre(&mut self) {
+        let rows = self.available_genres().len() + 1;
+        self.genre_index = (self.genre_index + 1) % rows;
+    }
+
+    pub fn previous_genre(&mut self) {
+        let rows = self.available_genres().len() + 1;
+        self.genre_index = (self.genre_index + rows - 1) % rows;
+    }
+
+    /// Filters the list to the highlighted genre, or clears the filter from the first row,
+    /// keeping the selected movie highlighted if it is still listed and the first otherwise
+    pub fn apply_genre_selection(&mut self) {
+        let selected = self.get_selected_movie_name();
+        self.genre_filter = self
+            .genre_index
+            .checked_sub(1)
+            .and_then(|index| self.available_genres().into_iter().nth(index));
+        self.genre_popup = false;
+        self.select_movie_by_name(&selected.unwrap_or_default());
+    }
+
+    pub fn open_jump_palette(&mut self) {
+        self.jump_palette = true;
+        self.jump_input.clear();
+        self.jump_index = 0;
+    }
+
+    /// Movies with a session still to come that fuzzy-match the palette input, best first,
+    /// each with its next session
+    pub fn get_jump_matches(&self) -> Vec<(String, DateTime<Local>)> {
+        let now = Local::now();
+        let mut matches: Vec<(i64, String, DateTime<Local>)> = self
+            .ritz_movie_times
+            .iter()
+            .filter_map(|(name, times)| {
+                let next = times.iter().filter(|time| **time >= now).min()?;
+                let score = fuzzy_score(&self.jump_input, name)?;
+                Some((score, name.clone(), *next))
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        matches
+            .into_iter()
+            .map(|(_, name, next)| (name, next))
+            .collect()
+    }
+
+    pub fn next_jump_match(&mut self) {
+        let count = self.get_jump_matches().len();
+        if count > 0 {
+            self.jump_index = (self.jump_index + 1) % count;
+        }
+    }
+
+    pub fn previous_jump_match(&mut self) {
+        let count = self.get_jump_matches().len();
+        if count > 0 {
+            self.jump_index = (self.jump_index + count - 1) % count;
+        }
+    }
+
+    /// Selects the highlighted palette movie on its next screening date and closes the palette
+    pub fn jump_to_match(&mut self) {
+        let Some((name, next)) = self.get_jump_matches().into_iter().nth(self.jump_index) else {
+            return;
+        };
+        self.jump_palette = false;
+        self.jump_input.clear();
+
+        let Some(date_index) = self
+            .visible_dates()
+            .iter()
+            .position(|date| date.date_naive() == self.config.movie_night(next))
+        else {
+            return;
+        };
+        self.selected_date_index = date_index;
+        self.selected_movie_index = self
+            .get_filtered_movies()
+            .iter()
+            .position(|(movie, _)| *movie == name)
+            .unwrap_or(0);
+        self.list_state.select(Some(self.selected_movie_index));
+        self.list_session_focus = false;
+        self.list_session_index = 0;
+        self.selection_changed_at = Some(Instant::now());
+        self.current_screen = CurrentScreen::Main;
+    }
+
+    /// Compares the selected date's times with what was shown for it last time, recording any
+    /// additions and removals, then snapshots them; called whenever the list is drawn
+    pub fn track_date_changes(&mut self) {
+        let Some(date) = self.get_selected_date().map(|date| date.date_naive()) else {
+            return;
+        };
+        // Unfiltered, so narrowing the list isn't mistaken for sessions being removed
+        let current = self.movie_times_on(date);
+
+        if let Some(previous) = self.date_snapshots.get(&date)
+            && *previous != current
+        {
+            let added = current
+                .iter()
+                .flat_map(|(name, times)| {
+                    let before = previous.get(name);
+                    times
+                        .iter()
+                        .filter(move |time| !before.is_some_and(|before| before.contains(time)))
+                        .map(|time| (name.clone(), *time))
+                })
+                .collect();
+            let mut removed = MovieTimes::new();
+            for (name, times) in previous {
+                let now = current.get(name);
+                for time in times {
+                    if !now.is_some_and(|now| now.contains(time)) {
+                        removed.entry(name.clone()).or_default().push(*time);
+                    }
+                }
+            }
+            self.date_changes = Some(DateChanges {
+                date,
+                added,
+                removed,
+                noticed_at: Instant::now(),
+            });
+        }
+        self.date_snapshots.insert(date, current);
+    }
+
+    /// Changes to highlight on the selected date, while they're still fresh
+    pub fn get_date_changes(&self) -> Option<&DateChanges> {
+        let date = self.get_selected_date()?.date_naive();
+        self.date_changes
+            .as_ref()
+            .filter(|changes| changes.date == date && changes.noticed_at.elapsed() < DATE_CHANGE_HIGHLIGHT)
+    }
+
+    /// Cached IMDb rating for sorting, with unrated movies below every rated one
+    fn cached_rating(&self, name: &str) -> f64 {
+        self.detail_cache
+            .get(name)
+            .and_then(|details| parse_rating(&details.imdb_rating))
+            .unwrap_or(f64::NEG_INFINITY)
+    }
+
+    /// Cycles the list through name, earliest showtime and rating order, keeping the
+    /// selected movie highlighted
+    pub fn cycle_list_sort(&mut self) {
+        let selected = self.get_selected_movie_name();
+        self.list_sort = match self.list_sort {
+            ListSort::Alphabetical => ListSort::EarliestShowtime,
+            ListSort::EarliestShowtime => ListSort::Rating,
+            ListSort::Rating => ListSort::Alphabetical,
+        };
+        if self.list_sort == ListSort::Rating && self.omdb_api_key.is_none() {
+            self.set_status("Ratings need an OMDb API key - only already fetched movies are ranked");
+        }
+        if let Some(name) = selected {
+            self.select_movie_by_name(&name);
+        }
+    }
+
+    /// How many listed movies have a rating to sort by, out of how many are listed
+    pub fn rated_count(&self) -> (usize, usize) {
+        let movies = self.get_filtered_movies();
+        let rated = movies
+            .iter()
+            .filter(|(name, _)| self.cached_rating(name).is_finite())
+            .count();
+        (rated, movies.len())
+    }
+
+    /// Groups movies sharing the selected movie's leading word at the top, or restores the normal sort
+    pub fn toggle_franchise_group(&mut self) {
+        let selected = self.get_selected_movie_name();
+        if self.franchise_group.take().is_none() {
+            self.franchise_group = selected.as_deref().and_then(franchise_key);
+        }
+
+        // Keep the highlight on the movie that was selected before regrouping
+        if let Some(name) = selected {
+            self.select_movie_by_name(&name);
+        }
+    }
+
+    /// Records the list's date, selection and scroll offset so the next run can reopen there
+    pub fn save_list_position(&mut self) {
+        let (Some(date), Some(movie)) = (self.get_selected_date(), self.get_selected_movie_name())
+        else {
+            return;
+        };
+        self.state.list_position = Some(ListPosition {
+            date: date.date_naive(),
+            movie,
+            offset: self.list_state.offset(),
+        });
+        save_state(&self.state);
+    }
+
+    /// Returns to the saved list position if its date is still listed; the movie falls back
+    /// to the first and the offset is clamped by the list when fewer movies are showing
+    fn restore_list_position(&mut self) {
+        let Some(position) = self.state.list_position.clone() else {
+            return;
+        };
+        let Some(date_index) = self
+            .visible_dates()
+            .iter()
+            .position(|date| date.date_naive() == position.date)
+        else {
+            return;
+        };
+
+        self.selected_date_index = date_index;
+        self.select_movie_by_name(&position.movie);
+        *self.list_state.offset_mut() = position.offset;
+    }
+
+    /// Starts editing the movie search; the list filters live as the term changes
+    pub fn start_search(&mut self) {
+        self.committed_search = self.search_term.clone();
+        self.searching = true;
+    }
+
+    /// Leaves the search box, keeping the list filtered by what was typed
+    pub fn commit_search(&mut self) {
+        self.committed_search = self.search_term.clone();
+        self.searching = false;
+    }
+
+    /// Leaves the search box, putting back the filter from before this edit
+    pub fn discard_search(&mut self) {
+        self.search_term = std::mem::take(&mut self.committed_search);
+        self.searching = false;
+        self.clamp_list_selection();
+    }
+
+    pub fn push_search_char(&mut self, c: char) {
+        self.search_term.push(c);
+        self.clamp_list_selection();
+    }
+
+    pub fn pop_search_char(&mut self) {
+        self.search_term.pop();
+        self.clamp_list_selection();
+    }
+
+    /// Drops the movie search so the full list for the date returns
+    pub fn clear_search(&mut self) {
+        self.searching = false;
+        self.search_term.clear();
+        self.committed_search.clear();
+        self.clamp_list_selection();
+    }
+
+    /// Pulls the date selection back onto the visible dates after they shrank, e.g. when
+    /// midnight hides yesterday or a refresh returns fewer days
+    pub fn clamp_date_selection(&mut self) {
+        let date_count = self.visible_dates().len();
+        if self.selected_date_index >= date_count {
+            self.selected_date_index = date_count.saturating_sub(1);
+            self.selected_movie_index = 0;
+            self.list_state.select(Some(0));
+            self.list_session_index = 0;
+        }
+    }
+
+    /// Pulls the selection back onto the list after it shrank, e.g. as sessions pass
+    pub fn clamp_list_selection(&mut self) {
+        let movie_count = self.get_filtered_movies().len();
+        if self.selected_movie_index >= movie_count {
+            self.selected_movie_index = movie_count.saturating_sub(1);
+            self.list_state.select(Some(self.selected_movie_index));
+            self.list_session_index = 0;
+        }
+    }
+
+    fn select_movie_by_name(&mut self, name: &str) {
+        let index = self
+            .get_filtered_movies()
+            .iter()
+            .position(|(movie, _)| movie == name)
+            .unwrap_or(0);
+        self.selected_movie_index = index;
+        self.list_state.select(Some(index));
+    }
+
+    pub fn get_selected_movie_name(&self) -> Option<String> {
+        let movies = self.get_filtered_movies();
+        movies.get(self.selected_movie_index).map(|(name, _)| name.clone())
+    }
+
+    /// Switches to the detail screen for `movie_name` and remembers it for quick reopening
+    pub fn open_movie_detail(&mut self, movie_name: String) {
+        self.state.last_viewed_movie = Some(movie_name.clone());
+        save_state(&self.state);
+        self.track(UsageAction::DetailOpen);
+
+        self.current_screen = CurrentScreen::MovieDetail;
+        self.detail_movie = Some(movie_name.clone());
+        self.detail_session_index = 0;
+        self.detail_session_focus = false;
+        self.reset_detail_search();
+        self.fetch_movie_detail(movie_name);
+    }
+
+    /// Reopens the most recently viewed movie's detail, even if the selection has moved
+    pub fn reopen_last_viewed(&mut self) {
+        if let Some(movie_name) = self.state.last_viewed_movie.clone() {
+            self.open_movie_detail(movie_name);
+        }
+    }
+
+    /// Whether the movie shown on the detail screen is still in the loaded showtimes
+    pub fn is_detail_movie_screening(&self) -> bool {
+        self.detail_movie
+            .as_ref()
+            .is_some_and(|name| self.ritz_movie_times.contains_key(name))
+    }
+
+    /// Upcoming sessions for the movie on the detail screen, with any scraped details
+    pub fn get_detail_sessions(&self) -> Vec<(DateTime<Local>, SessionInfo)> {
+        match &self.detail_movie {
+            Some(name) => self.get_upcoming_sessions(name),
+            None => Vec::new(),
+        }
+    }
+
+    /// A movie's sessions that haven't started yet, soonest first, with any scraped details
+    pub fn get_upcoming_sessions(&self, name: &str) -> Vec<(DateTime<Local>, SessionInfo)> {
+        let now = Local::now();
+
+        let mut sessions: Vec<(DateTime<Local>, SessionInfo)> = self
+            .ritz_movie_times
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter(|time| **time >= now)
+            .map(|time| {
+                let info = self
+                    .session_details
+                    .get(name)
+                    .and_then(|details| details.get(time))
+                    .cloned()
+                    .unwrap_or_default();
+                (*time, info)
+            })
+            .collect();
+        sessions.sort_by_key(|(time, _)| *time);
+        sessions
+    }
+
+    /// The selected list movie's sessions on the selected date, in time order, with any scraped details
+    pub fn get_list_sessions(&self) -> Vec<(DateTime<Local>, SessionInfo)> {
+        let movies = self.get_filtered_movies();
+        let Some((name, times)) = movies.get(self.selected_movie_index) else {
+            return Vec::new();
+        };
+
+        let mut sessions: Vec<(DateTime<Local>, SessionInfo)> = times
+            .iter()
+            .map(|time| {
+                let info = self
+                    .session_details
+                    .get(name)
+                    .and_then(|details| details.get(time))
+                    .cloned()
+                    .unwrap_or_default();
+                (*time, info)
+            })
+            .collect();
+        sessions.sort_by_key(|(time, _)| *time);
+        sessions
+    }
+
+    /// Switches h/l in the list between changing date and picking one of the selected movie's times
+    pub fn toggle_list_session_focus(&mut self) {
+        self.list_session_focus = !self.list_session_focus;
+        self.list_session_index = 0;
+    }
+
+    /// Expands the selected list movie inline, or collapses it if it's already expanded
+    pub fn toggle_expanded_movie(&mut self) {
+        let selected = self.get_selected_movie_name();
+        self.expanded_movie = if self.expanded_movie == selected {
+            None
+        } else {
+            selected
+        };
+    }
+
+    /// Moves focus to the next (or previous) of dates, list and times, wrapping around
+    pub fn cycle_focus(&mut self, forward: bool) {
+        const REGIONS: usize = 3;
+        let current = match (self.focus, self.list_session_focus) {
+            (_, true) => 2,
+            (Focus::Dates, false) => 0,
+            (Focus::List, false) => 1,
+        };
+        let next = if forward {
+            (current + 1) % REGIONS
+        } else {
+            (current + REGIONS - 1) % REGIONS
+        };
+
+        self.focus = if next == 0 { Focus::Dates } else { Focus::List };
+        self.list_session_focus = next == 2;
+        self.list_session_index = 0;
+    }
+
+    /// Whether h/l and ←→ on the main screen should change the date
+    pub fn dates_take_keys(&self) -> bool {
+        !self.config.focus_regions || self.focus == Focus::Dates
+    }
+
+    /// Whether j/k and ↑↓ on the main screen should move through the list
+    pub fn list_takes_keys(&self) -> bool {
+        !self.config.focus_regions || self.focus == Focus::List
+    }
+
+    /// Whether a main screen region should be drawn as focused
+    pub fn is_focused(&self, focus: Focus) -> bool {
+        self.config.focus_regions && !self.list_session_focus && self.focus == focus
+    }
+
+    pub fn next_list_session(&mut self) {
+        let count = self.get_list_sessions().len();
+        if count > 0 {
+            self.list_session_index = (self.list_session_index + 1) % count;
+        }
+    }
+
+    pub fn previous_list_session(&mut self) {
+        let count = self.get_list_sessions().len();
+        if count > 0 {
+            self.list_session_index = (self.list_session_index + count - 1) % count;
+        }
+    }
+
+    /// The focused list session with the movie it belongs to
+    pub fn get_popup_session(&self) -> Option<(String, DateTime<Local>, SessionInfo)> {
+        let name = self.get_selected_movie_name()?;
+        let (time, info) = self
+            .get_list_sessions()
+            .into_iter()
+            .nth(self.list_session_index)?;
+        Some((name, time, info))
+    }
+
+    pub fn open_session_popup(&mut self) {
+        if self.get_popup_session().is_some() {
+            self.session_popup = true;
+        }
+    }
+
+    /// Opens the booking page for the session shown in the popup
+    pub fn book_popup_session(&mut self) {
+        match self.get_popup_session() {
+            Some((_, _, SessionInfo { booking_url: Some(url), .. })) => {
+                self.track(UsageAction::Book);
+                self.open_url(&url);
+            }
+            Some(_) => self.set_status("No booking link was found for this session"),
+            None => self.session_popup = false,
+        }
+    }
+
+    pub fn next_detail_session(&mut self) {
+        let count = self.get_detail_sessions().len();
+        if count > 0 {
+            self.detail_session_index = (self.detail_session_index + 1) % count;
+        }
+    }
+
+    pub fn previous_detail_session(&mut self) {
+        let count = self.get_detail_sessions().len();
+        if count > 0 {
+            self.detail_session_index = (self.detail_session_index + count - 1) % count;
+        }
+    }
+
+    /// Opens the booking page for the highlighted session on the detail screen
+    pub fn book_detail_session(&mut self) {
+        let sessions = self.get_detail_sessions();
+        match sessions.get(self.detail_session_index) {
+            Some((_, SessionInfo { booking_url: Some(url), .. })) => {
+                let url = url.clone();
+                self.track(UsageAction::Book);
+                self.open_url(&url);
+            }
+            Some(_) => self.set_status("No booking link was found for this session"),
+            None => self.set_status("No upcoming sessions to book"),
+        }
+    }
+
+    /// Opens the selected movie's page on the cinema site, or failing that the page listing
+    /// the selected date
+    pub fn open_booking_page(&mut self) {
+        let movie_page = self
+            .get_selected_movie_name()
+            .and_then(|name| self.movie_pages.get(&name).cloned());
+        let day_page = || {
+            let date = self.get_selected_date()?.date_naive();
+            let (scraped_on, labels) = &self.day_labels;
+            if *scraped_on != Local::now().date_naive() {
+                return None;
+            }
+            labels.get(&date).map(|label| self.cinema.day_url(label))
+        };
+        match movie_page.or_else(day_page) {
+            Some(url) => {
+                self.track(UsageAction::Book);
+                self.open_url(&url);
+            }
+            None => self.set_status("No booking page is known for this movie - (g) refreshes"),
+        }
+    }
+
+    /// Writes the highlighted detail session to an .ics file in the export dir
+    pub fn export_detail_session(&mut self) {
+        let sessions = self.get_detail_sessions();
+        let (Some(name), Some((start, info))) =
+            (&self.detail_movie, sessions.get(self.detail_session_index))
+        else {
+            self.set_status("No upcoming sessions to export");
+            return;
+        };
+
+        let location = self.config.cinema_location();
+        let session = CalendarSession {
+            title: name,
+            start: *start,
+            runtime: self
+                .selected_movie_detail
+                .as_ref()
+                .and_then(|movie| known_value(&movie.runtime)),
+            location: &location,
+            url: info.booking_url.as_deref(),
+        };
+
+        let path = get_export_dir().join(format!(
+            "{}_{}.ics",
+            export_file_stem(name),
+            start.format("%Y%m%d_%H%M")
+        ));
+
+        match fs::write(&path, calendar(&[session])) {
+            Ok(()) => {
+                self.track(UsageAction::Export);
+                self.set_status(&format!("Exported session to {}", path.display()));
+            }
+            Err(e) => self.set_status(&format!("Couldn't export session: {}", e)),
+        }
+    }
+
+    /// Writes the selected list movie's sessions on the selected date to one .ics file
+    pub fn export_movie_day_calendar(&mut self) {
+        let (Some(date), Some((name, mut times))) = (
+            self.get_selected_date().map(|date| date.date_naive()),
+            self.get_filtered_movies().into_iter().nth(self.selected_movie_index),
+        ) else {
+            self.set_status("No movie selected");
+            return;
+        };
+        times.sort();
+
+        let location = self.config.cinema_location();
+        let runtime = self
+            .detail_cache
+            .get(&name)
+            .and_then(|movie| known_value(&movie.runtime));
+        let bookings = self.session_details.get(&name);
+        let sessions: Vec<CalendarSession> = times
+            .iter()
+            .map(|start| CalendarSession {
+                title: &name,
+                start: *start,
+                runtime,
+                location: &location,
+                url: bookings
+                    .and_then(|details| details.get(start))
+                    .and_then(|info| info.booking_url.as_deref()),
+            })
+            .collect();
+
+        let path = get_export_dir().join(format!(
+            "{}_{}.ics",
+            export_file_stem(&name),
+            date.format("%Y%m%d")
+        ));
+        let count = sessions.len();
+        match fs::write(&path, calendar(&sessions)) {
+            Ok(()) => {
+                self.track(UsageAction::Export);
+                self.set_status(&format!(
+                    "Exported {} session{} to {}",
+                    count,
+                    if count == 1 { "" } else { "s" },
+                    path.display()
+                ));
+            }
+            Err(e) => self.set_status(&format!("Couldn't export sessions: {}", e)),
+        }
+    }
+
+    /// Writes one .ics file per visible date holding every movie's sessions that day. Runtimes
+    /// come from details fetched this run; uncached movies get the default event length.
+    pub fn export_week_calendars(&mut self) {
+        let location = self.config.cinema_location();
+        let dates: Vec<NaiveDate> = self.visible_dates().iter().map(|date| date.date_naive()).collect();
+
+        let mut events = 0;
+        let mut files = 0;
+        for date in dates {
+            let mut sessions: Vec<CalendarSession> = self
+                .ritz_movie_times
+                .iter()
+                .flat_map(|(name, times)| times.iter().map(move |time| (name, *time)))
+                .filter(|(_, time)| self.config.movie_night(*time) == date)
+                .map(|(name, start)| CalendarSession {
+                    title: name,
+                    start,
+                    runtime: self
+                        .detail_cache
+                        .get(name)
+                        .and_then(|movie| known_value(&movie.runtime)),
+                    location: &location,
+                    url: self
+                        .session_details
+                        .get(name)
+                        .and_then(|details| details.get(&start))
+                        .and_then(|info| info.booking_url.as_deref()),
+                })
+                .collect();
+            if sessions.is_empty() {
+                continue;
+            }
+            sessions.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.title.cmp(b.title)));
+
+            let path = get_export_dir().join(format!("cinema_{}.ics", date.format("%Y%m%d")));
+            if let Err(e) = fs::write(&path, calendar(&sessions)) {
+                self.set_status(&format!("Couldn't export {}: {}", path.display(), e));
+                return;
+            }
+            events += sessions.len();
+            files += 1;
+        }
+
+        if files == 0 {
+            self.set_status("No sessions to export");
+            return;
+        }
+        self.track(UsageAction::Export);
+        self.set_status(&format!(
+            "Exported {} events to {} calendar file{} in {}",
+            events,
+            files,
+            if files == 1 { "" } else { "s" },
+            get_export_dir().display()
+        ));
+    }
+
+    /// Writes every loaded session with any OMDb details fetched this run to a CSV in the export dir
+    pub fn export_sessions_csv(&mut self) {
+        if self.ritz_movie_times.is_empty() {
+            self.set_status("No showtimes loaded to export");
+            return;
+        }
+
+        let path = get_export_dir().join(format!(
+            "cinema_sessions_{}.csv",
+            Local::now().format("%Y%m%d_%H%M")
+        ));
+        let csv = sessions_csv_detailed(&self.ritz_movie_times, &self.detail_cache);
+        match fs::write(&path, csv) {
+            Ok(()) => {
+                self.track(UsageAction::Export);
+                self.set_status(&format!("Exported sessions to {}", path.display()));
+            }
+            Err(e) => self.set_status(&format!("Couldn't export sessions: {}", e)),
+        }
+    }
+
+    /// Writes every loaded session to a JSON file in the export dir
+    pub fn export_sessions_json(&mut self) {
+        if self.ritz_movie_times.is_empty() {
+            self.set_status("No showtimes loaded to export");
+            return;
+        }
+
+        let path = get_export_dir().join(format!(
+            "cinema_sessions_{}.json",
+            Local::now().format("%Y%m%d_%H%M")
+        ));
+        match fs::write(&path, sessions_json(&self.ritz_movie_times, &self.detail_cache)) {
+            Ok(()) => {
+                self.track(UsageAction::Export);
+                self.set_status(&format!("Exported sessions to {}", path.display()));
+            }
+            Err(e) => self.set_status(&format!("Couldn't export sessions: {}", e)),
+        }
+    }
+
+    /// Copies the selected movie and its next session as a short message, e.g. "Dune — 7:30 PM today"
+    pub fn copy_session_text(&mut self) {
+        let movies = self.get_filtered_movies();
+        let Some((name, mut times)) = movies.into_iter().nth(self.selected_movie_index) else {
+            self.set_status("No movie selected");
+            return;
+        };
+        times.sort();
+
+        // The soonest session still to come, or the day's first when the date isn't today
+        let now = Local::now();
+        let Some(time) = times.iter().find(|time| **time >= now).or(times.first()) else {
+            self.set_status("No sessions to copy");
+            return;
+        };
+
+        let days_away = (time.date_naive() - now.date_naive()).num_days();
+        let day = match days_away {
+            0 => "today".to_string(),
+            1 => "tomorrow".to_string(),
+            _ => time.format(self.config.date_format.short()).to_string(),
+        };
+        let text = format!("{} — {} {}", name, format_showtime(time, self.config.use_24h), day);
+
+        match self.copy_to_clipboard(text.clone()) {
+            Ok(()) => {
+                self.track(UsageAction::Copy);
+                self.set_status(&format!("Copied \"{}\"", text));
+            }
+            Err(e) => self.set_status(&format!("Couldn't copy to clipboard: {}", e)),
+        }
+    }
+
+    /// Copies the selected movie with all its times on the selected date, e.g.
+    /// "Dune: Part Two — 2:00 PM, 5:30 PM, 8:45 PM"
+    pub fn copy_day_times(&mut self) {
+        let movies = self.get_filtered_movies();
+        let Some((name, mut times)) = movies.into_iter().nth(self.selected_movie_index) else {
+            self.set_status("No movie selected");
+            return;
+        };
+        if times.is_empty() {
+            self.set_status("No sessions to copy");
+            return;
+        }
+        times.sort();
+
+        let times: Vec<String> = times
+            .iter()
+            .map(|time| format_showtime(time, self.config.use_24h))
+            .collect();
+        let text = format!("{} — {}", name, times.join(", "));
+
+        match self.copy_to_clipboard(text.clone()) {
+            Ok(()) => {
+                self.track(UsageAction::Copy);
+                self.set_status(&format!("Copied \"{}\"", text));
+            }
+            Err(e) => self.set_status(&format!("Couldn't copy to clipboard: {}", e)),
+        }
+    }
+
+    /// Copies a Markdown link to the detail movie's IMDb page, e.g. "[Dune (2021)](https://www.imdb.com/title/tt1160419/)"
+    pub fn copy_imdb_link(&mut self) {
+        let Some(movie) = &self.selected_movie_detail else {
+            self.set_status("No movie details loaded");
+            return;
+        };
+        let Some(imdb_id) = known_value(&movie.imdb_id) else {
+            self.set_status("No IMDb page is known for this movie");
+            return;
+        };
+
+        let label = match known_value(&movie.year) {
+            Some(year) => format!("{} ({})", movie.title, year),
+            None => movie.title.clone(),
+        };
+        let link = format!("[{}](https://www.imdb.com/title/{}/)", label, imdb_id);
+
+        match self.copy_to_clipboard(link.clone()) {
+            Ok(()) => {
+                self.track(UsageAction::Copy);
+                self.set_status(&format!("Copied {}", link));
+            }
+            Err(e) => self.set_status(&format!("Couldn't copy to clipboard: {}", e)),
+        }
+    }
+
+    /// Opens the detail movie's IMDb page in the browser, when OMDb gave an ID
+    pub fn open_imdb_page(&mut self) {
+        let Some(movie) = &self.selected_movie_detail else {
+            self.set_status("No movie details loaded");
+            return;
+        };
+        let Some(imdb_id) = known_value(&movie.imdb_id) else {
+            self.set_status("No IMDb page is known for this movie");
+            return;
+        };
+        let url = format!("https://www.imdb.com/title/{}/", imdb_id);
+        self.open_url(&url);
+    }
+
+    /// Starts editing the note for the movie on the detail screen, or the selected one in the list
+    pub fn start_note_edit(&mut self) {
+        let movie = match self.current_screen {
+            CurrentScreen::MovieDetail => self.detail_movie.clone(),
+            _ => self.get_selected_movie_name(),
+        };
+        let Some(movie) = movie else {
+            return;
+        };
+        self.note_input = self.get_note(&movie).unwrap_or_default().to_string();
+        self.editing_note = Some(movie);
+    }
+
+    /// Saves the typed note, removing it when left empty
+    pub fn save_note(&mut self) {
+        let Some(movie) = self.editing_note.take() else {
+            return;
+        };
+        let note = std::mem::take(&mut self.note_input).trim().to_string();
+        if note.is_empty() {
+            if self.state.notes.remove(&movie).is_some() {
+                self.set_status(&format!("Removed the note for {}", movie));
+            }
+        } else {
+            self.state.notes.insert(movie, note);
+        }
+        save_state(&self.state);
+    }
+
+    pub fn cancel_note_edit(&mut self) {
+        self.editing_note = None;
+        self.note_input.clear();
+    }
+
+    pub fn get_note(&self, movie: &str) -> Option<&str> {
+        self.state.notes.get(movie).map(String::as_str)
+    }
+
+    /// Queues a reminder for a session, going off `reminder_lead_minutes` before it starts
+    pub fn add_reminder(&mut self, movie: String, session: DateTime<Local>) {
+        if session <= Local::now() {
+            self.set_status("That session has already started");
+            return;
+        }
+        if self
+            .state
+            .reminders
+            .iter()
+            .any(|reminder| reminder.movie == movie && reminder.session == session)
+        {
+            self.set_status("A reminder is already set for this session");
+            return;
+        }
+
+        let reminder = Reminder {
+            movie,
+            session,
+            lead_minutes: self.config.reminder_lead_minutes.max(0),
+            fired: false,
+        };
+        let fires_at = reminder.fires_at();
+        self.state.reminders.push(reminder);
+        self.state.reminders.sort_by_key(Reminder::fires_at);
+        save_state(&self.state);
+        self.set_status(&format!(
+            "Reminder set for {} {}",
+            format_showtime(&fires_at, self.config.use_24h),
+            fires_at.format("%a")
+        ));
+    }
+
+    pub fn remind_popup_session(&mut self) {
+        if let Some((name, time, _)) = self.get_popup_session() {
+            self.add_reminder(name, time);
+        }
+    }
+
+    pub fn remind_detail_session(&mut self) {
+        let sessions = self.get_detail_sessions();
+        match (&self.detail_movie, sessions.get(self.detail_session_index)) {
+            (Some(name), Some((time, _))) => self.add_reminder(name.clone(), *time),
+            _ => self.set_status("No upcoming sessions to remind about"),
+        }
+    }
+
+    pub fn next_reminder(&mut self) {
+        let count = self.state.reminders.len();
+        if count > 0 {
+            let next = self.reminder_state.selected().map_or(0, |i| (i + 1) % count);
+            self.reminder_state.select(Some(next));
+        }
+    }
+
+    pub fn previous_reminder(&mut self) {
+        let count = self.state.reminders.len();
+        if count > 0 {
+            let previous = match self.reminder_state.selected() {
+                Some(0) | None => count - 1,
+                Some(i) => (i - 1).min(count - 1),
+            };
+            self.reminder_state.select(Some(previous));
+        }
+    }
+
+    pub fn remove_selected_reminder(&mut self) {
+        let Some(index) = self.reminder_state.selected() else {
+            return;
+        };
+        if index >= self.state.reminders.len() {
+            return;
+        }
+
+        let reminder = self.state.reminders.remove(index);
+        save_state(&self.state);
+        self.reminder_state
+            .select(Some(index.min(self.state.reminders.len().saturating_sub(1))));
+        self.set_status(&format!("Removed reminder for {}", reminder.movie));
+    }
+
+    /// Raises an alert for the soonest favorite session today starting within
+    /// `favorite_alert_minutes`, once per session; run every tick
+    pub fn check_favorite_sessions(&mut self) {
+        let now = Local::now();
+        if self.imminent_alert.as_ref().is_some_and(|(_, session)| *session <= now) {
+            self.imminent_alert = None;
+        }
+        if self.config.favorite_alert_minutes <= 0 || self.favorites.is_empty() {
+            return;
+        }
+
+        let window_end = now + chrono::Duration::minutes(self.config.favorite_alert_minutes);
+        let today = self.config.today();
+        let soonest = self
+            .favorites
+            .iter()
+            .filter_map(|name| {
+                let times = self.ritz_movie_times.get(name)?;
+                times
+                    .iter()
+                    .filter(|time| {
+                        self.config.movie_night(**time) == today
+                            && **time > now
+                            && **time <= window_end
+                    })
+                    .min()
+                    .map(|time| (name.clone(), *time))
+            })
+            .min_by_key(|(_, time)| *time);
+
+        if let Some(session) = soonest
+            && self.alerted_sessions.insert(session.clone())
+        {
+            log::info!("Alerting about {} at {}", session.0, session.1);
+            self.imminent_alert = Some(session);
+        }
+    }
+
+    /// The favorite alert's message, e.g. "★ Dune starts in 25 min (7:30 PM)"
+    pub fn imminent_alert_text(&self) -> Option<String> {
+        let (name, session) = self.imminent_alert.as_ref()?;
+        let minutes = (*session - Local::now()).num_minutes().max(0);
+        let starts = match minutes {
+            0 => "starts now".to_string(),
+            _ => format!("starts in {} min", minutes),
+        };
+        Some(format!(
+            "★ {} {} ({})",
+            name,
+            starts,
+            format_showtime(session, self.config.use_24h)
+        ))
+    }
+
+    /// Fires reminders that are due and drops those whose session has started; run every tick
+    pub fn check_reminders(&mut self) {
+        let now = Local::now();
+        let before = self.state.reminders.len();
+        self.state.reminders.retain(|reminder| reminder.session > now);
+        let mut changed = self.state.reminders.len() != before;
+
+        let mut due = Vec::new();
+        for reminder in &mut self.state.reminders {
+            if !reminder.fired && reminder.fires_at() <= now {
+                reminder.fired = true;
+                due.push(format!(
+                    "Reminder: {} starts at {}",
+                    reminder.movie,
+                    format_showtime(&reminder.session, self.config.use_24h)
+                ));
+            }
+        }
+
+        // The footer shows one message at a time, so simultaneous reminders are combined
+        if !due.is_empty() {
+            changed = true;
+            self.set_status(&due.join(" | "));
+            // Ring the terminal bell so a reminder is noticed when the window isn't being watched.
+            // The UI draws to stderr, so the bell goes there too rather than into stdout
+            let _ = execute!(io::stderr(), Print('\x07'));
+            io::stderr().flush().ok();
+        }
+        if changed {
+            save_state(&self.state);
+        }
+    }
+
+    /// Starts typing a correction for the detail movie's OMDb match, prefilled with any existing one
+    pub fn start_match_correction(&mut self) {
+        let Some(name) = &self.detail_movie else {
+            return;
+        };
+        self.match_input = self.state.imdb_overrides.get(name).cloned().unwrap_or_default();
+        self.correcting_match = true;
+    }
+
+    /// Saves the typed IMDb ID as the detail movie's match and refetches its details;
+    /// an empty entry removes the correction and goes back to searching by title
+    pub fn apply_match_correction(&mut self) {
+        let Some(name) = self.detail_movie.clone() else {
+            self.correcting_match = false;
+            return;
+        };
+        let input = self.match_input.trim();
+
+        if input.is_empty() {
+            self.state.imdb_overrides.remove(&name);
+            self.set_status("Removed the IMDb match correction");
+        } else {
+            let Some(imdb_id) = parse_imdb_id(input) else {
+                self.set_status("Not an IMDb ID - expected something like tt1160419");
+                return;
+            };
+            self.set_status(&format!("Matching \"{}\" to {}", name, imdb_id));
+            self.state.imdb_overrides.insert(name.clone(), imdb_id);
+        }
+        save_state(&self.state);
+
+        self.correcting_match = false;
+        self.match_input.clear();
+        self.forget_detail(&name);
+        self.reset_detail_search();
+        self.fetch_movie_detail(name);
+    }
+
+    fn copy_to_clipboard(&mut self, text: String) -> Result<(), arboard::Error> {
+        let clipboard = match &mut self.clipboard {
+            Some(clipboard) => clipboard,
+            None => self.clipboard.insert(arboard::Clipboard::new()?),
+        };
+        clipboard.set_text(text)
+    }
+
+    /// Counts a use of `action` when usage stats are enabled
+    pub fn track(&mut self, action: UsageAction) {
+        if let Some(usage) = &mut self.usage {
+            usage.record(action);
+            save_usage(usage);
+        }
+    }
+
+    /// Opens `url` in the browser, reporting the outcome in the footer
+    pub fn open_url(&mut self, url: &str) {
+        match open_in_browser(url) {
+            Ok(()) => self.set_status(&format!("Opened {}", url)),
+            Err(e) => self.set_status(&format!("Couldn't open browser: {}", e)),
+        }
+    }
+
+    pub fn open_directions(&mut self) {
+        let url = self.config.directions_url();
+        self.open_url(&url);
+    }
+
+    /// Timing report for the last scrape, when benchmark mode is on
+    pub fn benchmark_report(&self) -> Option<String> {
+        if self.benchmark && !self.scrape_timings.is_empty() {
+            Some(format_timings(&self.scrape_timings))
+        } else {
+            None
+        }
+    }
+
+    /// Asks for confirmation before quitting, remembering the screen to go back to
+    pub fn confirm_quit(&mut self) {
+        if self.current_screen != CurrentScreen::Exiting {
+            self.previous_screen = Some(self.current_screen);
+            self.current_screen = CurrentScreen::Exiting;
+        }
+    }
+
+    /// Declines the quit confirmation and returns to where it was asked from
+    pub fn cancel_quit(&mut self) {
+        self.current_screen = self.previous_screen.take().unwrap_or(CurrentScreen::Main);
+    }
+
+    /// The screen drawn underneath any quit confirmation
+    pub fn visible_screen(&self) -> CurrentScreen {
+        match self.current_screen {
+            CurrentScreen::Exiting => self.previous_screen.unwrap_or(CurrentScreen::Main),
+            screen => screen,
+        }
+    }
+
+    /// The current animation frame. Counted from a monotonic clock, so spinners turn at the
+    /// same pace however often the screen redraws and even when the system clock jumps.
+    pub fn animation_frame(&self) -> u64 {
+        (self.animation_start.elapsed().as_millis() / ANIMATION_FRAME.as_millis()) as u64
+    }
+
+    /// Shows a short-lived message in the footer
+    pub fn set_status(&mut self, message: &str) {
+        self.status_message = Some((message.to_string(), Instant::now()));
+    }
+
+    pub fn set_error(&mut self, message: &str) {
+        self.last_error = Some((message.to_string(), Instant::now()));
+    }
+
+    /// The error banner's message, if it hasn't been dismissed or expired
+    pub fn get_error(&self) -> Option<&str> {
+        self.last_error
+            .as_ref()
+            .filter(|(_, set_at)| set_at.elapsed() < ERROR_BANNER_DURATION)
+            .map(|(message, _)| message.as_str())
+    }
+
+    /// The footer message, if it hasn't expired yet
+    pub fn get_status(&self) -> Option<&str> {
+        self.status_message
+            .as_ref()
+            .filter(|(_, set_at)| set_at.elapsed() < STATUS_MESSAGE_DURATION)
+            .map(|(message, _)| message.as_str())
+    }
+
+    pub fn fetch_movie_detail(&mut self, movie_name: String) {
+        if self.omdb_api_key.is_none() {
+            self.movie_detail_error = Some("API key not set".to_string());
+            self.omdb_error = None;
+            self.loading_movie_detail = false;
+            return;
+        }
+
+        // A poster still downloading belongs to the previous movie
+        self.detail_generation += 1;
+        self.clear_poster();
+        self.movie_detail_error = None;
+        self.omdb_error = None;
+        self.refreshing_detail = false;
+
+        // Details stored with the other plot length are fetched again, as are old matches a
+        // background fetch stored without looking for a recent film of the same name. That
+        // look happens once; its result is stored as checked either way.
+        let entry = self.stored_details.get(&detail_key(&movie_name));
+        let plot_matches = entry.is_none_or(|entry| entry.plot == self.config.plot);
+        let remake_checked = entry.is_none_or(|entry| {
+            entry.recent_checked || !may_be_remake(&movie_name, &entry.details.year)
+        });
+        if plot_matches
+            && remake_checked
+            && let Some(details) = self.detail_cache.get(&movie_name).cloned()
+        {
+            self.detail_receiver = None;
+            self.show_movie_detail(details);
+            return;
+        }
+
+        let sender = replace_channel(&mut self.detail_receiver);
+        self.loading_movie_detail = true;
+        self.selected_movie_detail = None;
+        self.movie_detail_error = None;
+        self.omdb_error = None;
+
+        let api_key = self.omdb_api_key.clone().unwrap();
+        let timeout = self.omdb_timeout();
+        let imdb_id = self.state.imdb_overrides.get(&movie_name).cloned();
+        let options = LookupOptions {
+            movies_only: self.config.omdb_movie_search(&movie_name),
+            plot: self.config.plot,
+            recent_fallback: true,
+        };
+        let generation = self.detail_generation;
+
+        let on_panic = move |error| Some(MovieDetailMessage::Error(generation, error, None));
+        let worker = spawn_request(sender, on_panic, move |sender| {
+            let mut on_retry = |message| {
+                let _ = sender.send(MovieDetailMessage::Retrying(generation, message));
+            };
+            let result = http_client().map_err(Into::into).and_then(|client| {
+                crate::app::omd::fetch_movie_details(
+                    client,
+                    timeout,
+                    &movie_name,
+                    imdb_id.as_deref(),
+                    options,
+                    &api_key,
+                    &mut on_retry,
+                )
+            });
+            match result {
                 Ok(details) => {
-                    let _ = sender.send(MovieDetailMessage::Complete(details));
+                    let _ = sender.send(MovieDetailMessage::Complete(
+                        generation,
+                        movie_name,
+                        Box::new(details),
+                    ));
                 }
                 Err(e) => {
-                    let _ = sender.send(MovieDetailMessage::Error(e.to_string()));
+                    let refusal = e.downcast_ref::<OmdbError>().cloned();
+                    let _ = sender.send(MovieDetailMessage::Error(
+                        generation,
+                        e.to_string(),
+                        refusal,
+                    ));
+                }
+            }
+        });
+        self.keep_worker(worker);
+    }
+
+    /// Re-fetches the detail movie from OMDb, replacing its cached details once they arrive
+    pub fn refresh_movie_detail(&mut self) {
+        let Some(movie_name) = self.detail_movie.clone() else {
+            return;
+        };
+        self.forget_detail(&movie_name);
+        self.fetch_movie_detail(movie_name);
+        self.refreshing_detail = self.loading_movie_detail;
+    }
+
+    /// Displays fetched details on the detail screen, caching them and starting the poster download
+    pub fn show_movie_detail(&mut self, details: Welcome) {
+        self.refreshing_detail = false;
+        if let Some(name) = self.detail_movie.clone() {
+            self.cache_detail(name, details.clone(), true);
+        }
+        let poster_url = details.poster.clone();
+        self.selected_movie_detail = Some(details);
+        self.loading_movie_detail = false;
+
+        if self.config.detail_posters && is_valid_poster_url(&poster_url) {
+            self.fetch_poster(poster_url);
+        }
+    }
+
+    /// Runtimes in minutes for movies whose OMDb details have been fetched
+    pub fn get_cached_runtimes(&self) -> HashMap<String, i64> {
+        self.detail_cache
+            .iter()
+            .filter_map(|(name, details)| {
+                parse_runtime_minutes(&details.runtime).map(|minutes| (name.clone(), minutes))
+            })
+            .collect()
+    }
+
+    /// Other screening movies sharing a genre with the detail movie, most shared genres first,
+    /// with the genres they share. Only movies whose details have been fetched can be compared.
+    pub fn get_similar_movies(&self) -> Vec<(String, Vec<String>)> {
+        let (Some(current), Some(movie)) = (&self.detail_movie, &self.selected_movie_detail) else {
+            return Vec::new();
+        };
+        let genres = split_genres(&movie.genre);
+
+        let mut similar: Vec<(String, Vec<String>)> = self
+            .detail_cache
+            .iter()
+            .filter(|(name, _)| *name != current && self.ritz_movie_times.contains_key(*name))
+            .filter_map(|(name, details)| {
+                let shared: Vec<String> = split_genres(&details.genre)
+                    .into_iter()
+                    .filter(|genre| genres.contains(genre))
+                    .collect();
+                (!shared.is_empty()).then(|| (name.clone(), shared))
+            })
+            .collect();
+        similar.sort_by(|(a_name, a_shared), (b_name, b_shared)| {
+            b_shared.len().cmp(&a_shared.len()).then(a_name.cmp(b_name))
+        });
+        similar.truncate(SIMILAR_LIMIT);
+        similar
+    }
+
+    /// Opens the detail screen for the `index`th similar movie (zero-based)
+    pub fn open_similar_movie(&mut self, index: usize) {
+        if let Some((name, _)) = self.get_similar_movies().into_iter().nth(index) {
+            self.open_movie_detail(name);
+        }
+    }
+
+    /// Whether a movie matches a configured interest, by title or by any OMDb details fetched for it
+    pub fn is_interesting(&self, name: &str) -> bool {
+        let mut fields = vec![name];
+        if let Some(details) = self.detail_cache.get(name) {
+            fields.extend([
+                details.genre.as_str(),
+                details.director.as_str(),
+                details.actors.as_str(),
+                details.title.as_str(),
+            ]);
+        }
+        self.config.matches_interest(&fields)
+    }
+
+    /// Once the selection has rested, fetches details and posters in the background for the
+    /// selected movie and the ones just above and below it, one request at a time and only
+    /// where nothing is cached yet. Results only go to the caches, never the detail screen.
+    pub fn preload_selected_detail(&mut self) {
+        if !self.config.preload_details
+            || self.omdb_api_key.is_none()
+            || self.preloading.is_some()
+            || self.poster_prefetch_receiver.is_some()
+            || !matches!(self.current_screen, CurrentScreen::Main)
+        {
+            return;
+        }
+        if let Some(changed_at) = self.selection_changed_at {
+            // Nothing is prefetched while the selection is still moving
+            if changed_at.elapsed() < PRELOAD_DEBOUNCE {
+                return;
+            }
+            self.selection_changed_at = None;
+            self.prefetch_queue = self.prefetch_candidates();
+        }
+
+        // A movie stays queued while its details load, so its poster can follow
+        while let Some(movie_name) = self.prefetch_queue.front().cloned() {
+            let Some(details) = self.detail_cache.get(&movie_name) else {
+                if self.preload_failed.contains(&movie_name) {
+                    self.prefetch_queue.pop_front();
+                    continue;
                 }
+                self.start_preload(movie_name);
+                return;
+            };
+            let poster_url = details.poster.clone();
+            self.prefetch_queue.pop_front();
+            if self.config.detail_posters
+                && self.caching_enabled
+                && is_valid_poster_url(&poster_url)
+                && !is_poster_cached(&poster_url)
+            {
+                self.start_poster_prefetch(poster_url);
+                return;
+            }
+        }
+    }
+
+    /// The selected movie followed by its neighbours in the list
+    fn prefetch_candidates(&self) -> VecDeque<String> {
+        let movies = self.get_filtered_movies();
+        let index = self.selected_movie_index;
+        [Some(index), index.checked_sub(1), index.checked_add(1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|i| movies.get(i).map(|(name, _)| name.clone()))
+            .collect()
+    }
+
+    fn start_poster_prefetch(&mut self, poster_url: String) {
+        let sender = replace_channel(&mut self.poster_prefetch_receiver);
+        let timeout = self.omdb_timeout();
+
+        // A failed download is simply tried again when the movie is opened
+        let worker = spawn_request(sender, |_| Some(()), move |sender| {
+            if let Ok(client) = http_client() {
+                let _ = prefetch_poster(client, timeout, &poster_url);
+            }
+            let _ = sender.send(());
+        });
+        self.keep_worker(worker);
+    }
+
+    /// While sorting or filtering by rating, fetches details for the date's movies one at a
+    /// time so their ratings can be ranked
+    pub fn enrich_ratings(&mut self) {
+        if (self.list_sort != ListSort::Rating && self.min_rating.is_none())
+            || self.omdb_api_key.is_none()
+            || self.preloading.is_some()
+            || !matches!(self.current_screen, CurrentScreen::Main)
+        {
+            return;
+        }
+        // Unrated movies may be hidden by the filter, so look past the list to the whole date
+        let candidates: Vec<String> = match self.get_selected_date() {
+            Some(date) if self.min_rating.is_some() => {
+                self.movie_times_on(date.date_naive()).into_keys().collect()
+            }
+            _ => self.get_filtered_movies().into_iter().map(|(name, _)| name).collect(),
+        };
+        let missing = candidates.into_iter().find(|name| {
+            !self.detail_cache.contains_key(name) && !self.preload_failed.contains(name)
+        });
+        if let Some(movie_name) = missing {
+            self.start_preload(movie_name);
+        }
+    }
+
+    fn start_preload(&mut self, movie_name: String) {
+        let sender = replace_channel(&mut self.preload_receiver);
+        self.preloading = Some(movie_name.clone());
+        let api_key = self.omdb_api_key.clone().unwrap();
+        let timeout = self.omdb_timeout();
+        let imdb_id = self.state.imdb_overrides.get(&movie_name).cloned();
+        let options = LookupOptions {
+            movies_only: self.config.omdb_movie_search(&movie_name),
+            plot: self.config.plot,
+            recent_fallback: false,
+        };
+
+        // Failures are left for the detail screen to report if the movie is opened
+        let worker = spawn_request(sender, |_| None, move |sender| {
+            if let Ok(client) = http_client()
+                && let Ok(details) =
+                    crate::app::omd::fetch_movie_details(
+                        client,
+                        timeout,
+                        &movie_name,
+                        imdb_id.as_deref(),
+                        options,
+                        &api_key,
+                        &mut |_| {},
+                    )
+            {
+                let _ = sender.send((movie_name, Box::new(details)));
             }
         });
+        self.keep_worker(worker);
+    }
+
+    /// Moves a finished preload into the detail cache
+    pub fn poll_preload(&mut self) {
+        if let Some(receiver) = &self.poster_prefetch_receiver
+            && !matches!(receiver.try_recv(), Err(mpsc::TryRecvError::Empty))
+        {
+            self.poster_prefetch_receiver = None;
+        }
+        let Some(receiver) = &self.preload_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok((movie_name, details)) => {
+                // A new rating can reorder the list, so keep the highlight on the same movie
+                let selected = self.get_selected_movie_name();
+                self.cache_detail(movie_name, *details, false);
+                if (self.list_sort == ListSort::Rating || self.min_rating.is_some())
+                    && let Some(name) = selected
+                {
+                    self.select_movie_by_name(&name);
+                }
+            }
+            Err(mpsc::TryRecvError::Empty) => return,
+            Err(mpsc::TryRecvError::Disconnected) => {
+                if let Some(movie_name) = self.preloading.clone() {
+                    self.preload_failed.insert(movie_name);
+                }
+            }
+        }
+        self.preload_receiver = None;
+        self.preloading = None;
     }
 
     pub fn fetch_poster(&mut self, poster_url: String) {
-        let (sender, receiver) = mpsc::channel();
-        self.poster_receiver = Some(receiver);
+        let sender = replace_channel(&mut self.poster_receiver);
         self.loading_poster = true;
+        self.poster_error = None;
         self.poster_protocol = None;
 
         // Clone the picker for the thread
         let picker = self.picker.clone();
+        let timeout = self.omdb_timeout();
+        let use_cache = self.caching_enabled;
+        let generation = self.detail_generation;
 
-        std::thread::spawn(move || {
-            match crate::app::omd::download_poster(&poster_url, &picker) {
+        let on_panic = move |error| Some(PosterMessage::Error(generation, error));
+        let worker = spawn_request(sender, on_panic, move |sender| {
+            let result = http_client().map_err(Into::into).and_then(|client| {
+                crate::app::omd::download_poster(
+                    client,
+                    timeout,
+                    &poster_url,
+                    &picker,
+                    use_cache,
+                    &mut |_| {},
+                )
+            });
+            match result {
                 Ok(protocol) => {
-                    let _ = sender.send(PosterMessage::Complete(protocol));
+                    let _ = sender.send(PosterMessage::Complete(generation, protocol));
                 }
                 Err(e) => {
-                    let _ = sender.send(PosterMessage::Error(e.to_string()));
+                    let _ = sender.send(PosterMessage::Error(generation, e.to_string()));
                 }
             }
         });
+        self.keep_worker(worker);
+    }
+
+    /// Whether a detail or poster result belongs to the fetch the detail screen is waiting on
+    pub fn is_current_detail(&self, generation: u64) -> bool {
+        generation == self.detail_generation
+    }
+
+    /// Looks up one known film in the background so a bad or exhausted key is reported
+    /// straight away rather than when a movie is first opened
+    pub fn check_api_key(&mut self) {
+        let Some(api_key) = self.omdb_api_key.clone() else {
+            return;
+        };
+        let sender = replace_channel(&mut self.key_check_receiver);
+        let timeout = self.omdb_timeout();
+
+        // Network trouble says nothing about the key, so only OMDb's refusals are sent
+        let worker = spawn_request(sender, |_| None, move |sender| {
+            if let Ok(client) = http_client()
+                && let Err(error) = check_api_key(client, timeout, &api_key)
+                && let Some(refusal) = error.downcast_ref::<OmdbError>()
+            {
+                let _ = sender.send(refusal.clone());
+            }
+        });
+        self.keep_worker(worker);
+    }
+
+    pub fn poll_key_check(&mut self) {
+        let Some(receiver) = &self.key_check_receiver else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(refusal) => {
+                self.key_check_receiver = None;
+                let remedy = match refusal {
+                    OmdbError::InvalidKey => {
+                        "check omdb_api_key in the config file or pass --api-key"
+                    }
+                    OmdbError::RateLimited => "movie details will work again tomorrow",
+                    OmdbError::NotFound(_) => return,
+                };
+                self.set_error(&format!("{} - {}", refusal, remedy));
+            }
+            Err(mpsc::TryRecvError::Empty) => {}
+            Err(mpsc::TryRecvError::Disconnected) => self.key_check_receiver = None,
+        }
+    }
+
+    /// How long OMDb detail and poster requests may take
+    fn omdb_timeout(&self) -> Duration {
+        Duration::from_secs(self.config.omdb_timeout_secs)
+    }
+
+    pub fn scroll_detail_down(&mut self) {
+        self.detail_scroll = self.detail_scroll.saturating_add(1);
+    }
+
+    pub fn scroll_detail_up(&mut self) {
+        self.detail_scroll = self.detail_scroll.saturating_sub(1);
+    }
+
+    /// Scrolls a page, keeping a line of overlap; the renderer clamps it to the content
+    pub fn page_detail_down(&mut self) {
+        let page = self.detail_page_height.saturating_sub(1).max(1);
+        self.detail_scroll = self.detail_scroll.saturating_add(page);
+    }
+
+    pub fn page_detail_up(&mut self) {
+        let page = self.detail_page_height.saturating_sub(1).max(1);
+        self.detail_scroll = self.detail_scroll.saturating_sub(page);
+    }
+
+    pub fn open_help(&mut self) {
+        self.show_help = true;
+        self.help_scroll = 0;
+    }
+
+    /// Scrolls the help by `lines`, down when positive; the renderer clamps it to the content
+    pub fn scroll_help(&mut self, lines: isize) {
+        self.help_scroll = self.help_scroll.saturating_add_signed(lines);
+    }
+
+    /// Scrolls the help a page, keeping a line of overlap
+    pub fn page_help(&mut self, down: bool) {
+        let page = self.help_page_height.saturating_sub(1).max(1) as isize;
+        self.scroll_help(if down { page } else { -page });
+    }
+
+    /// Switches showtimes between 12- and 24-hour clocks and saves the choice to the config file
+    pub fn toggle_24h(&mut self) {
+        self.config.use_24h = !self.config.use_24h;
+        let clock = if self.config.use_24h { "24-hour" } else { "12-hour" };
+        match save_config_value("use_24h", self.config.use_24h.into()) {
+            Ok(()) => self.set_status(&format!("Showing {} times", clock)),
+            Err(e) => self.set_status(&format!("Showing {} times (couldn't save: {})", clock, e)),
+        }
+    }
+
+    /// Switches between OMDb's short and full plots, fetching the open movie's again
+    pub fn toggle_plot_length(&mut self) {
+        self.config.plot = match self.config.plot {
+            PlotLength::Short => PlotLength::Full,
+            PlotLength::Full => PlotLength::Short,
+        };
+        let length = self.config.plot.query_value();
+        match save_config_value("plot", serde_json::json!(self.config.plot)) {
+            Ok(()) => self.set_status(&format!("Showing {} plots", length)),
+            Err(e) => self.set_status(&format!("Showing {} plots (couldn't save: {})", length, e)),
+        }
+        self.refresh_movie_detail();
+    }
+
+    /// Clears any in-screen find and returns the detail content to the top
+    pub fn reset_detail_search(&mut self) {
+        self.detail_scroll = 0;
+        self.detail_searching = false;
+        self.detail_search.clear();
+    }
+
+    fn clear_poster(&mut self) {
+        self.poster_protocol = None;
+        self.loading_poster = false;
+        self.poster_error = None;
+        self.poster_receiver = None;
+    }
+
+    /// Abandons any in-flight detail or poster fetch and clears the detail screen state
+    pub fn close_movie_detail(&mut self) {
+        self.detail_generation += 1;
+        self.detail_movie = None;
+        self.reset_detail_search();
+        self.selected_movie_detail = None;
+        self.movie_detail_error = None;
+        self.omdb_error = None;
+        self.loading_movie_detail = false;
+        self.refreshing_detail = false;
+        self.detail_receiver = None;
+        self.clear_poster();
+        self.clamp_list_selection();
+    }
+}
+
+/// Builds the poster picker. A protocol from `CINEMA_TUI_IMAGE_PROTOCOL` or the config replaces
+/// the detected one, though the terminal is still asked for its font size unless halfblocks
+/// were requested. Returns the picker along with how its protocol was chosen.
+fn image_picker(config: &Config) -> (Picker, String) {
+    let env_value = std::env::var("CINEMA_TUI_IMAGE_PROTOCOL").ok();
+    let (requested, source) = match env_value.as_deref().map(ImageProtocol::from_name) {
+        Some(Some(protocol)) => (protocol, "CINEMA_TUI_IMAGE_PROTOCOL".to_string()),
+        Some(None) => (
+            ImageProtocol::Auto,
+            format!(
+                "unknown CINEMA_TUI_IMAGE_PROTOCOL \"{}\" ignored",
+                env_value.unwrap_or_default()
+            ),
+        ),
+        None => (config.image_protocol, "config".to_string()),
+    };
+
+    let forced = match requested {
+        ImageProtocol::Auto => None,
+        ImageProtocol::Halfblocks => return (Picker::halfblocks(), format!("halfblocks ({})", source)),
+        ImageProtocol::Sixel => Some(ProtocolType::Sixel),
+        ImageProtocol::Kitty => Some(ProtocolType::Kitty),
+        ImageProtocol::Iterm2 => Some(ProtocolType::Iterm2),
+    };
+
+    // Some terminals and SSH sessions never answer, so the wait is capped
+    let options = QueryStdioOptions {
+        timeout: Duration::from_millis(config.image_query_timeout_ms),
+        ..QueryStdioOptions::default()
+    };
+    let (mut picker, detected) = match Picker::from_query_stdio_with_options(options) {
+        Ok(picker) => (picker, "auto-detected"),
+        Err(_) => (Picker::halfblocks(), "terminal didn't answer"),
+    };
+    let source = match forced {
+        Some(protocol) => {
+            picker.set_protocol_type(protocol);
+            source
+        }
+        None if source == "config" => detected.to_string(),
+        None => format!("{}, {}", detected, source),
+    };
+    let description = format!("{} ({})", protocol_name(picker.protocol_type()), source);
+    (picker, description)
+}
+
+fn protocol_name(protocol: ProtocolType) -> &'static str {
+    match protocol {
+        ProtocolType::Halfblocks => "halfblocks",
+        ProtocolType::Sixel => "sixel",
+        ProtocolType::Kitty => "kitty",
+        ProtocolType::Iterm2 => "iterm2",
+    }
+}
+
+/// The local midnight of every night with a showtime, in order. Nights are collected as
+/// calendar days, so a daylight-saving change can't shift or repeat a date.
+fn showtime_dates(movie_times: &MovieTimes, config: &Config) -> Vec<DateTime<Local>> {
+    showtime_nights(movie_times.values().flatten().copied(), config, &Local)
+}
+
+/// `showtime_dates` for session times in any zone
+fn showtime_nights<Tz: TimeZone>(
+    times: impl IntoIterator<Item = DateTime<Tz>>,
+    config: &Config,
+    tz: &Tz,
+) -> Vec<DateTime<Tz>> {
+    let dates: BTreeSet<NaiveDate> = times.into_iter().map(|time| config.movie_night(time)).collect();
+    dates.into_iter().map(|date| midnight_in(date, tz)).collect()
+}
+
+/// Creates a fresh channel for a background fetch, dropping any previous receiver so
+/// results from an abandoned fetch fail to send instead of being applied to the wrong state
+fn replace_channel<T>(slot: &mut Option<mpsc::Receiver<T>>) -> mpsc::Sender<T> {
+    let (sender, receiver) = mpsc::channel();
+    *slot = Some(receiver);
+    sender
+}
+
+/// A movie title made safe for a file name, e.g. "AC/DC: Live" -> "AC_DC__Live"
+fn export_file_stem(title: &str) -> String {
+    title
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Detail cache key, so listings differing only in case or stray spaces share an entry
+fn detail_key(title: &str) -> String {
+    title.trim().to_lowercase()
+}
+
+/// Parses OMDb's "7.8" style rating, `None` for "N/A" or anything unexpected
+fn parse_rating(rating: &str) -> Option<f64> {
+    rating.trim().parse().ok()
+}
+
+/// Splits OMDb's comma-separated genre field, skipping "N/A"
+fn split_genres(genre: &str) -> Vec<String> {
+    genre
+        .split(',')
+        .filter_map(known_value)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Extracts the leading word of a title used to group franchises (e.g. "spider-man")
+fn franchise_key(title: &str) -> Option<String> {
+    let first = title
+        .split_whitespace()
+        .next()?
+        .trim_end_matches(|c: char| !c.is_alphanumeric());
+    if first.is_empty() {
+        None
+    } else {
+        Some(first.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDateTime, NaiveTime};
+    use chrono_tz::{Australia::Sydney, Tz};
+
+    /// Sydney, where the cinema is, moved its clocks forward at 2am on 4 October 2026
+    fn in_sydney(local: &str) -> DateTime<Tz> {
+        let time = NaiveDateTime::parse_from_str(local, "%Y-%m-%d %H:%M").unwrap();
+        Sydney.from_local_datetime(&time).single().unwrap()
+    }
+
+    #[test]
+    fn showtimes_keep_their_dates_across_a_dst_change() {
+        let times = vec![
+            in_sydney("2026-10-03 23:30"),
+            in_sydney("2026-10-04 00:30"),
+            in_sydney("2026-10-04 03:15"),
+            in_sydney("2026-10-04 21:00"),
+            in_sydney("2026-10-05 00:15"),
+            in_sydney("2026-10-05 10:00"),
+        ];
+        let dates = showtime_nights(times, &Config::default(), &Sydney);
+        let days: Vec<NaiveDate> = dates.iter().map(|date| date.date_naive()).collect();
+        assert_eq!(
+            days,
+            vec![
+                NaiveDate::from_ymd_opt(2026, 10, 3).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 10, 4).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 10, 5).unwrap(),
+            ]
+        );
+        for date in &dates {
+            assert_eq!(date.time(), NaiveTime::MIN);
+        }
+    }
+
+    #[test]
+    fn late_sessions_stay_on_their_night_across_a_dst_change() {
+        let config = Config {
+            day_rollover_hour: 3,
+            ..Config::default()
+        };
+        // Only two real hours pass between midnight and 3:15am on the 4th, but by the clock
+        // it is past the rollover, so that session starts the 4th's night
+        let nights: Vec<NaiveDate> = [
+            in_sydney("2026-10-04 01:30"),
+            in_sydney("2026-10-04 03:15"),
+            in_sydney("2026-10-05 01:30"),
+        ]
+        .into_iter()
+        .map(|time| config.movie_night(time))
+        .collect();
+        assert_eq!(
+            nights,
+            vec![
+                NaiveDate::from_ymd_opt(2026, 10, 3).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 10, 4).unwrap(),
+                NaiveDate::from_ymd_opt(2026, 10, 4).unwrap(),
+            ]
+        );
     }
 }
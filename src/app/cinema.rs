@@ -0,0 +1,753 @@
+//! Cinema-independent scraping: a [`CinemaSource`] knows one chain's site, and
+//! [`scrape_movies`] drives any source through the week with a small worker pool.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, mpsc};
+use std::thread;
+use std::time::{self, Instant};
+
+use crate::app::utils::get_offset_from_string;
+use crate::app::{FetchedShowtimes, MovieFetchMessage, MovieTimes, SessionDetails, SessionInfo};
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone};
+use rand::Rng;
+
+/// A single session time as listed on a day page
+pub struct Showtime {
+    /// As printed on the page, e.g. "7:30 pm"
+    pub time: String,
+    /// Session format tag such as "OC" or "3D"; `None` for a standard session
+    pub format: Option<String>,
+    pub booking_url: Option<String>,
+}
+
+/// A movie as listed on a day page
+pub struct Listing {
+    pub title: String,
+    /// The movie's own page on the cinema site, where its sessions can be booked
+    pub page_url: Option<String>,
+    pub showtimes: Vec<Showtime>,
+}
+
+/// One cinema chain's website. Fetching and parsing a day are separate so parsing can be
+/// timed on its own, and exercised against saved pages.
+pub trait CinemaSource: Send + Sync {
+    /// The days to scrape, as (midnight of the day, the label the site addresses it by),
+    /// at least `min_days` where the site allows and `extra_days` past its last listed day
+    fn available_days(
+        &self,
+        min_days: usize,
+        extra_days: usize,
+        on_event: &mut dyn FnMut(ScrapeEvent),
+    ) -> Result<Vec<(DateTime<Local>, String)>, String>;
+
+    /// The address of the page listing one day's sessions, by the day's label
+    fn day_url(&self, day: &str) -> String;
+
+    /// Downloads the page listing one day's sessions, reporting retries to `on_retry`
+    fn fetch_day(&self, day: &str, on_retry: &mut dyn FnMut(String)) -> Result<String, String>;
+
+    /// Every movie on a day page with its sessions
+    fn parse_day(&self, html: &str) -> Vec<Listing>;
+
+    /// Whether `day` is further ahead than the site is sure to list. Failing to fetch one
+    /// leaves it out with a warning instead of failing the scrape.
+    fn is_look_ahead(&self, _day: &str) -> bool {
+        false
+    }
+}
+
+/// Narrows another source to the one day it lists for `date`, so that date can be refreshed
+/// without fetching the rest of the week. No day is a look-ahead here: failing to fetch it
+/// fails the refresh rather than clearing its sessions.
+pub struct SingleDay<'a> {
+    pub source: &'a dyn CinemaSource,
+    pub date: NaiveDate,
+}
+
+impl CinemaSource for SingleDay<'_> {
+    fn available_days(
+        &self,
+        min_days: usize,
+        extra_days: usize,
+        on_event: &mut dyn FnMut(ScrapeEvent),
+    ) -> Result<Vec<(DateTime<Local>, String)>, String> {
+        let days: Vec<_> = self
+            .source
+            .available_days(min_days, extra_days, on_event)?
+            .into_iter()
+            .filter(|(date, _)| date.date_naive() == self.date)
+            .collect();
+        if days.is_empty() {
+            return Err(format!(
+                "{} isn't listed on the cinema site any more",
+                self.date.format("%a %-d %b")
+            ));
+        }
+        Ok(days)
+    }
+
+    fn day_url(&self, day: &str) -> String {
+        self.source.day_url(day)
+    }
+
+    fn fetch_day(&self, day: &str, on_retry: &mut dyn FnMut(String)) -> Result<String, String> {
+        self.source.fetch_day(day, on_retry)
+    }
+
+    fn parse_day(&self, html: &str) -> Vec<Listing> {
+        self.source.parse_day(html)
+    }
+}
+
+/// How long each stage of scraping a single day took
+pub struct DayTiming {
+    pub label: String,
+    pub fetch: time::Duration,
+    pub parse: time::Duration,
+    pub sleep: time::Duration,
+}
+
+/// Events reported while scraping, so callers decide how to surface progress
+pub enum ScrapeEvent {
+    Progress(String),
+    /// Days with showtimes in hand out of the days being fetched, once those are known
+    DayProgress { done: usize, total: usize },
+    Timing(DayTiming),
+    /// Something looked wrong but scraping carried on
+    Warning(String),
+}
+
+/// Formats per-day timings into a report showing where the scrape spent its time
+pub fn format_timings(timings: &[DayTiming]) -> String {
+    let mut report = String::from("Scrape timings:\n");
+    for timing in timings {
+        report.push_str(&format!(
+            "  {:<10} fetch {:>6}ms  parse {:>4}ms  sleep {:>5}ms\n",
+            timing.label,
+            timing.fetch.as_millis(),
+            timing.parse.as_millis(),
+            timing.sleep.as_millis()
+        ));
+    }
+
+    let work: time::Duration = timings.iter().map(|t| t.fetch + t.parse).sum();
+    let sleep: time::Duration = timings.iter().map(|t| t.sleep).sum();
+    report.push_str(&format!(
+        "  Total: {:.1}s fetching/parsing, {:.1}s sleeping between requests\n",
+        work.as_secs_f64(),
+        sleep.as_secs_f64()
+    ));
+    report
+}
+
+/// Days already scraped, handed back on failure so a retry only fetches the rest
+pub struct PartialScrape {
+    /// Day endpoints fully scraped so far
+    pub labels: HashSet<String>,
+    pub movie_times: MovieTimes,
+    pub sessions: SessionDetails,
+    /// The label each scraped date was fetched by
+    pub day_labels: HashMap<NaiveDate, String>,
+    pub movie_pages: HashMap<String, String>,
+    /// Day labels like "today" are relative, so progress is only reusable on the same day
+    started_on: NaiveDate,
+}
+
+impl Default for PartialScrape {
+    fn default() -> Self {
+        Self {
+            labels: HashSet::new(),
+            movie_times: HashMap::new(),
+            sessions: HashMap::new(),
+            day_labels: HashMap::new(),
+            movie_pages: HashMap::new(),
+            started_on: Local::now().date_naive(),
+        }
+    }
+}
+
+impl PartialScrape {
+    /// Whether this progress can still be resumed, i.e. it is from today
+    pub fn is_current(&self) -> bool {
+        self.started_on == Local::now().date_naive()
+    }
+
+    /// The key to file a scraped title under, so the same film listed as "THE BATMAN" one day
+    /// and "The Batman" the next shares one entry. Mixed-case titles win over all-caps ones
+    /// for display, renaming any entry filed under the shouting version.
+    fn movie_key(&mut self, scraped: &str) -> String {
+        let title = scraped.trim();
+        let normalized = title.to_lowercase();
+        let Some(existing) = self
+            .movie_times
+            .keys()
+            .find(|name| name.to_lowercase() == normalized)
+            .cloned()
+        else {
+            return title.to_string();
+        };
+
+        let shouting = |name: &str| !name.chars().any(char::is_lowercase);
+        if existing != title && shouting(&existing) && !shouting(title) {
+            if let Some(times) = self.movie_times.remove(&existing) {
+                self.movie_times.insert(title.to_string(), times);
+            }
+            if let Some(sessions) = self.sessions.remove(&existing) {
+                self.sessions.insert(title.to_string(), sessions);
+            }
+            if let Some(page) = self.movie_pages.remove(&existing) {
+                self.movie_pages.insert(title.to_string(), page);
+            }
+            return title.to_string();
+        }
+        existing
+    }
+
+    fn fail(self, error: String) -> ScrapeFailure {
+        ScrapeFailure {
+            error,
+            partial: Box::new(self),
+        }
+    }
+}
+
+/// A scrape that stopped partway, with whatever days it managed to fetch
+pub struct ScrapeFailure {
+    pub error: String,
+    pub partial: Box<PartialScrape>,
+}
+
+/// Warning shown whenever a scrape runs without the delay between requests
+pub const FAST_SCRAPE_WARNING: &str =
+    "⚠ Fast mode: no delay between requests - the site may block you. For development only.";
+
+/// Day pages fetched at once; each worker still pauses between its own requests
+const SCRAPE_WORKERS: usize = 3;
+
+/// One day page's listings, with how long fetching and parsing it took
+type ScrapedDay = (Vec<Listing>, DayTiming);
+
+/// A worker's report on one day page
+enum DayMessage {
+    Started(String),
+    /// A request for the day failed and is being retried
+    Retrying(String),
+    Finished(DateTime<Local>, String, Result<ScrapedDay, String>),
+}
+
+/// Reported for a day abandoned because the scrape was cancelled
+const CANCELLED: &str = "Cancelled";
+
+/// How often a pausing worker checks whether the scrape was cancelled
+const CANCEL_POLL: time::Duration = time::Duration::from_millis(50);
+
+/// Sleeps for `duration` in short slices, returning `false` as soon as `cancel` is set
+fn pause(duration: time::Duration, cancel: &AtomicBool) -> bool {
+    let deadline = Instant::now() + duration;
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return false;
+        }
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return true;
+        }
+        thread::sleep(left.min(CANCEL_POLL));
+    }
+}
+
+/// Waits `sleep_time`, which keeps a worker from hammering the site, then fetches and
+/// parses one day page
+fn scrape_day(
+    source: &dyn CinemaSource,
+    date_label: &str,
+    sleep_time: time::Duration,
+    cancel: &AtomicBool,
+    on_retry: &mut dyn FnMut(String),
+) -> Result<ScrapedDay, String> {
+    if !pause(sleep_time, cancel) {
+        return Err(CANCELLED.to_string());
+    }
+
+    let fetch_start = Instant::now();
+    let html = source.fetch_day(date_label, on_retry).map_err(|e| {
+        log::error!("Failed to fetch {}: {}", date_label, e);
+        format!("Failed to fetch {}: {}", date_label, e)
+    })?;
+    let fetch_time = fetch_start.elapsed();
+
+    if cancel.load(Ordering::Relaxed) {
+        return Err(CANCELLED.to_string());
+    }
+
+    let parse_start = Instant::now();
+    let listings = source.parse_day(&html);
+    log::info!(
+        "{}: {} movies, {} showtimes parsed",
+        date_label,
+        listings.len(),
+        listings.iter().map(|listing| listing.showtimes.len()).sum::<usize>()
+    );
+    let timing = DayTiming {
+        label: date_label.to_string(),
+        fetch: fetch_time,
+        parse: parse_start.elapsed(),
+        sleep: sleep_time,
+    };
+    Ok((listings, timing))
+}
+
+/// When a session `offset` minutes into `day` by the clock starts. Counting from midnight
+/// instead would land an hour out after a daylight-saving change that night. `None` for a
+/// time the change skips.
+fn session_start<Tz: TimeZone>(day: NaiveDate, offset: i64, tz: &Tz) -> Option<DateTime<Tz>> {
+    let seconds = u32::try_from(offset * 60).ok()?;
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0)?;
+    tz.from_local_datetime(&day.and_time(time)).earliest()
+}
+
+/// Scrapes the whole week of showtimes from `source`, reporting progress to `on_event`.
+/// `extra_days` asks for days past the last one the source lists.
+/// Each worker pauses a random `delay` of milliseconds between its requests, so no time is
+/// spent after the last one; fast mode passes `0..=0`. First requests are staggered by one
+/// delay per worker, so the workers don't all hit the site at once.
+/// Setting `cancel` stops workers before their next day or during a pause, failing the scrape.
+pub fn scrape_movies(
+    source: &dyn CinemaSource,
+    min_days: usize,
+    extra_days: usize,
+    delay: RangeInclusive<u64>,
+    cancel: &AtomicBool,
+    resume: PartialScrape,
+    mut on_event: impl FnMut(ScrapeEvent),
+) -> Result<FetchedShowtimes, ScrapeFailure> {
+    let mut on_event = |event: ScrapeEvent| {
+        if let ScrapeEvent::Warning(warning) = &event {
+            log::warn!("{}", warning);
+        }
+        on_event(event);
+    };
+    let mut partial = resume;
+
+    let dates = match source.available_days(min_days, extra_days, &mut on_event) {
+        Ok(dates) => dates,
+        Err(e) => return Err(partial.fail(format!("Failed to get dates: {}", e))),
+    };
+
+    let pending: Vec<(DateTime<Local>, String)> = dates
+        .into_iter()
+        .filter(|(_, date_label)| {
+            let done = partial.labels.contains(date_label);
+            if done {
+                on_event(ScrapeEvent::Progress(format!(
+                    "Keeping movie times already fetched for {}",
+                    date_label
+                )));
+            }
+            !done
+        })
+        .collect();
+    let total = pending.len();
+    // Days kept from a failed refresh count as done, so a retry's gauge starts part full
+    let kept = partial.labels.len();
+    on_event(ScrapeEvent::DayProgress {
+        done: kept,
+        total: kept + total,
+    });
+
+    // Workers pull days from a shared queue and report back here, so merging and progress
+    // stay on this thread. After a failure no new days are started, but days already in
+    // flight still land in `partial` for the retry to keep.
+    let queue = Mutex::new(pending.into_iter());
+    let stop = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+    let mut error = None;
+
+    thread::scope(|scope| {
+        for worker in 0..SCRAPE_WORKERS.min(total) {
+            let sender = sender.clone();
+            let (queue, stop, delay) = (&queue, &stop, delay.clone());
+            scope.spawn(move || {
+                let mut first = true;
+                while !stop.load(Ordering::Relaxed) && !cancel.load(Ordering::Relaxed) {
+                    let Some((date, date_label)) =
+                        queue.lock().ok().and_then(|mut days| days.next())
+                    else {
+                        break;
+                    };
+                    let _ = sender.send(DayMessage::Started(date_label.clone()));
+                    let mut on_retry = |message| {
+                        let message = format!("{}: {}", date_label, message);
+                        let _ = sender.send(DayMessage::Retrying(message));
+                    };
+                    // need to randomise this so we don't get blocked
+                    let mut sleep_time =
+                        time::Duration::from_millis(rand::thread_rng().gen_range(delay.clone()));
+                    if first {
+                        sleep_time *= worker as u32;
+                    }
+                    first = false;
+                    let result =
+                        scrape_day(source, &date_label, sleep_time, cancel, &mut on_retry);
+                    if result.is_err() && !source.is_look_ahead(&date_label) {
+                        stop.store(true, Ordering::Relaxed);
+                    }
+                    let _ = sender.send(DayMessage::Finished(date, date_label, result));
+                }
+            });
+        }
+        drop(sender);
+
+        let mut finished = 0;
+        for message in receiver {
+            let (date, date_label, result) = match message {
+                DayMessage::Started(date_label) => {
+                    on_event(ScrapeEvent::Progress(format!(
+                        "Getting movie times for {}",
+                        date_label
+                    )));
+                    continue;
+                }
+                DayMessage::Retrying(message) => {
+                    on_event(ScrapeEvent::Progress(message));
+                    continue;
+                }
+                DayMessage::Finished(date, date_label, result) => (date, date_label, result),
+            };
+            let (listings, timing) = match result {
+                Ok(day) => day,
+                // A day further ahead than the site lists yet is left out, not fatal
+                Err(e) if e != CANCELLED && source.is_look_ahead(&date_label) => {
+                    on_event(ScrapeEvent::Warning(e));
+                    finished += 1;
+                    on_event(ScrapeEvent::DayProgress {
+                        done: kept + finished,
+                        total: kept + total,
+                    });
+                    continue;
+                }
+                Err(e) => {
+                    error.get_or_insert(e);
+                    continue;
+                }
+            };
+            on_event(ScrapeEvent::Timing(timing));
+
+            for listing in listings {
+                let movie_name = partial.movie_key(&listing.title);
+                if let Some(page) = listing.page_url {
+                    partial.movie_pages.insert(movie_name.clone(), page);
+                }
+                for showtime in listing.showtimes {
+                    // One unreadable time shouldn't cost the rest of the week
+                    let Some(offset) = get_offset_from_string(&showtime.time) else {
+                        on_event(ScrapeEvent::Warning(format!(
+                            "Skipped a {} session on {} with an unreadable time \"{}\"",
+                            movie_name, date_label, showtime.time
+                        )));
+                        continue;
+                    };
+                    let Some(datetime) = session_start(date.date_naive(), offset, &Local) else {
+                        on_event(ScrapeEvent::Warning(format!(
+                            "Skipped a {} session on {} at {}, which the clocks skip that night",
+                            movie_name, date_label, showtime.time
+                        )));
+                        continue;
+                    };
+
+                    partial.movie_times.entry(movie_name.clone()).or_default().push(datetime);
+                    partial.sessions.entry(movie_name.clone()).or_default().insert(
+                        datetime,
+                        SessionInfo {
+                            booking_url: showtime.booking_url,
+                            format: showtime.format,
+                        },
+                    );
+                }
+            }
+            partial.labels.insert(date_label.clone());
+            partial.day_labels.insert(date.date_naive(), date_label.clone());
+            finished += 1;
+            on_event(ScrapeEvent::Progress(format!(
+                "Got movie times for {} ({}/{})",
+                date_label, finished, total
+            )));
+            on_event(ScrapeEvent::DayProgress {
+                done: kept + finished,
+                total: kept + total,
+            });
+        }
+    });
+
+    if cancel.load(Ordering::Relaxed) {
+        return Err(partial.fail(CANCELLED.to_string()));
+    }
+    if let Some(error) = error {
+        return Err(partial.fail(error));
+    }
+
+    // Overlapping day pages, or differently cased listings of one film, can repeat a
+    // session; dropping repeats here keeps them out of the list and the cache
+    for times in partial.movie_times.values_mut() {
+        times.sort();
+        times.dedup();
+    }
+
+    Ok(FetchedShowtimes {
+        movie_times: partial.movie_times,
+        sessions: partial.sessions,
+        day_labels: partial.day_labels,
+        movie_pages: partial.movie_pages,
+    })
+}
+
+/// Passes scrape progress on to the app as fetch messages
+fn forward_events(sender: &mpsc::Sender<MovieFetchMessage>) -> impl FnMut(ScrapeEvent) + '_ {
+    move |event| match event {
+        ScrapeEvent::Progress(message) => {
+            let _ = sender.send(MovieFetchMessage::Progress(message));
+        }
+        ScrapeEvent::DayProgress { done, total } => {
+            let _ = sender.send(MovieFetchMessage::DayProgress { done, total });
+        }
+        ScrapeEvent::Timing(timing) => {
+            let _ = sender.send(MovieFetchMessage::Timing(timing));
+        }
+        ScrapeEvent::Warning(warning) => {
+            let _ = sender.send(MovieFetchMessage::Warning(warning));
+        }
+    }
+}
+
+/// Runs the scrape on the current thread, forwarding progress and the result over `sender`.
+/// A cancelled scrape sends no result; the receiver is gone by then anyway.
+pub fn get_movies_threaded(
+    source: &dyn CinemaSource,
+    sender: mpsc::Sender<MovieFetchMessage>,
+    min_days: usize,
+    extra_days: usize,
+    delay: RangeInclusive<u64>,
+    cancel: &AtomicBool,
+    resume: PartialScrape,
+) {
+    let on_event = forward_events(&sender);
+    let result = scrape_movies(source, min_days, extra_days, delay, cancel, resume, on_event);
+    if cancel.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let _ = match result {
+        Ok(fetched) => sender.send(MovieFetchMessage::Complete(fetched)),
+        Err(failure) => {
+            log::error!("Scrape failed: {}", failure.error);
+            sender.send(MovieFetchMessage::Error(failure))
+        }
+    };
+}
+
+/// Like [`get_movies_threaded`] for the one day at `date`, finishing with a
+/// [`MovieFetchMessage::DayComplete`] that carries only that day's showtimes
+pub fn get_day_threaded(
+    source: &dyn CinemaSource,
+    sender: mpsc::Sender<MovieFetchMessage>,
+    date: NaiveDate,
+    min_days: usize,
+    extra_days: usize,
+    delay: RangeInclusive<u64>,
+    cancel: &AtomicBool,
+) {
+    let source = SingleDay { source, date };
+    let on_event = forward_events(&sender);
+    let result = scrape_movies(
+        &source,
+        min_days,
+        extra_days,
+        delay,
+        cancel,
+        PartialScrape::default(),
+        on_event,
+    );
+    if cancel.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let _ = match result {
+        Ok(fetched) => sender.send(MovieFetchMessage::DayComplete(date, fetched)),
+        Err(failure) => {
+            log::error!("Refreshing {} failed: {}", date, failure.error);
+            sender.send(MovieFetchMessage::Error(failure))
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::utils::local_midnight;
+    use chrono::{Duration, Offset};
+    use chrono_tz::Australia::Sydney;
+
+    /// A site whose "tomorrow" and "friday" pages are the same day, listing overlapping
+    /// sessions in different orders and casings
+    struct OverlappingDays {
+        friday: DateTime<Local>,
+    }
+
+    fn showtime(time: &str) -> Showtime {
+        Showtime {
+            time: time.to_string(),
+            format: None,
+            booking_url: None,
+        }
+    }
+
+    impl CinemaSource for OverlappingDays {
+        fn available_days(
+            &self,
+            _min_days: usize,
+            _extra_days: usize,
+            _on_event: &mut dyn FnMut(ScrapeEvent),
+        ) -> Result<Vec<(DateTime<Local>, String)>, String> {
+            Ok(vec![
+                (self.friday, "tomorrow".to_string()),
+                (self.friday, "friday".to_string()),
+            ])
+        }
+
+        fn day_url(&self, day: &str) -> String {
+            day.to_string()
+        }
+
+        fn fetch_day(&self, day: &str, _on_retry: &mut dyn FnMut(String)) -> Result<String, String> {
+            Ok(day.to_string())
+        }
+
+        fn parse_day(&self, html: &str) -> Vec<Listing> {
+            let (title, times) = match html {
+                "tomorrow" => ("Wicked", ["7:30 pm", "1:00 pm", "7:30 pm"]),
+                _ => ("WICKED", ["1:00 pm", "9:45 pm", "7:30 pm"]),
+            };
+            vec![Listing {
+                title: title.to_string(),
+                page_url: None,
+                showtimes: times.into_iter().map(showtime).collect(),
+            }]
+        }
+    }
+
+    #[test]
+    fn overlapping_day_pages_give_sorted_unique_times() {
+        let friday = local_midnight(NaiveDate::from_ymd_opt(2026, 10, 16).unwrap());
+        let source = OverlappingDays { friday };
+
+        let fetched = scrape_movies(
+            &source,
+            0,
+            0,
+            0..=0,
+            &AtomicBool::new(false),
+            PartialScrape::default(),
+            |_| {},
+        )
+        .unwrap_or_else(|failure| panic!("scrape failed: {}", failure.error));
+
+        assert_eq!(fetched.movie_times.len(), 1);
+        for times in fetched.movie_times.values() {
+            let mut expected = times.clone();
+            expected.sort();
+            expected.dedup();
+            assert_eq!(*times, expected);
+        }
+        let times = &fetched.movie_times["Wicked"];
+        let at = |hour: i64, minute: i64| friday + Duration::minutes(hour * 60 + minute);
+        assert_eq!(*times, vec![at(13, 0), at(19, 30), at(21, 45)]);
+    }
+
+    #[test]
+    fn sessions_keep_their_clock_times_across_a_dst_change() {
+        // Sydney's clocks went forward from 2am to 3am on 4 October 2026
+        let day = NaiveDate::from_ymd_opt(2026, 10, 4).unwrap();
+        let start = |time: &str| {
+            let offset = get_offset_from_string(time).unwrap();
+            session_start(day, offset, &Sydney).unwrap()
+        };
+
+        let sessions = [start("1:30 am"), start("1:00 pm"), start("9:45 pm")];
+        let clock: Vec<String> =
+            sessions.iter().map(|time| time.format("%H:%M").to_string()).collect();
+        assert_eq!(clock, ["01:30", "13:00", "21:45"]);
+        let hours_east = sessions.map(|time| time.offset().fix().local_minus_utc() / 3600);
+        assert_eq!(hours_east, [10, 11, 11]);
+
+        assert!(session_start(day, 2 * 60 + 30, &Sydney).is_none());
+    }
+
+    /// A site that lists today but not yet the dated day after it
+    struct ShortListing {
+        today: DateTime<Local>,
+    }
+
+    impl CinemaSource for ShortListing {
+        fn available_days(
+            &self,
+            _min_days: usize,
+            _extra_days: usize,
+            _on_event: &mut dyn FnMut(ScrapeEvent),
+        ) -> Result<Vec<(DateTime<Local>, String)>, String> {
+            Ok(vec![
+                (self.today, "today".to_string()),
+                (self.today + chrono::Days::new(7), "next".to_string()),
+            ])
+        }
+
+        fn day_url(&self, day: &str) -> String {
+            day.to_string()
+        }
+
+        fn fetch_day(&self, day: &str, _on_retry: &mut dyn FnMut(String)) -> Result<String, String> {
+            match day {
+                "today" => Ok(day.to_string()),
+                _ => Err("not listed yet".to_string()),
+            }
+        }
+
+        fn parse_day(&self, _html: &str) -> Vec<Listing> {
+            vec![Listing {
+                title: "Wicked".to_string(),
+                page_url: None,
+                showtimes: vec![showtime("7:30 pm")],
+            }]
+        }
+
+        fn is_look_ahead(&self, day: &str) -> bool {
+            day == "next"
+        }
+    }
+
+    #[test]
+    fn unlisted_look_ahead_days_are_left_out_with_a_warning() {
+        let today = local_midnight(NaiveDate::from_ymd_opt(2026, 10, 16).unwrap());
+        let source = ShortListing { today };
+        let mut warnings = Vec::new();
+
+        let fetched = scrape_movies(
+            &source,
+            0,
+            0,
+            0..=0,
+            &AtomicBool::new(false),
+            PartialScrape::default(),
+            |event| {
+                if let ScrapeEvent::Warning(warning) = event {
+                    warnings.push(warning);
+                }
+            },
+        )
+        .unwrap_or_else(|failure| panic!("scrape failed: {}", failure.error));
+
+        assert_eq!(fetched.movie_times["Wicked"], vec![today + Duration::minutes(19 * 60 + 30)]);
+        assert_eq!(warnings, ["Failed to fetch next: not listed yet"]);
+    }
+}
@@ -0,0 +1,497 @@
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+use crate::app::utils::write_atomic;
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+/// How movie titles too long for the list width are displayed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleOverflow {
+    /// Cut the title at the list width and end it with an ellipsis
+    #[default]
+    Truncate,
+    /// Continue the title onto additional lines
+    Wrap,
+}
+
+/// How a movie's session times too long for the list width are displayed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimesOverflow {
+    /// Continue the times onto additional lines, making the item taller
+    #[default]
+    Wrap,
+    /// Show the times that fit followed by a "+N" count of the rest
+    Truncate,
+}
+
+/// What separates movies in the main list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListSeparator {
+    /// Items directly follow each other
+    #[default]
+    None,
+    /// An empty line between items
+    Blank,
+    /// A thin horizontal rule between items
+    Rule,
+}
+
+/// Date ordering presets used wherever a calendar date is shown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateFormat {
+    /// Month before day, e.g. "Fri 10/17" and "October 17, 2025"
+    #[default]
+    Us,
+    /// Day before month, e.g. "Fri 17/10" and "17 October 2025"
+    Eu,
+    /// Year-month-day, e.g. "Fri 10-17" and "2025-10-17"
+    Iso,
+}
+
+impl DateFormat {
+    /// Compact weekday-and-date format for tight spaces
+    pub fn short(&self) -> &'static str {
+        match self {
+            DateFormat::Us => "%a %m/%d",
+            DateFormat::Eu => "%a %d/%m",
+            DateFormat::Iso => "%a %m-%d",
+        }
+    }
+
+    /// Full date format without the weekday
+    pub fn long(&self) -> &'static str {
+        match self {
+            DateFormat::Us => "%B %d, %Y",
+            DateFormat::Eu => "%d %B %Y",
+            DateFormat::Iso => "%Y-%m-%d",
+        }
+    }
+}
+
+/// A showtime as shown anywhere in the app, on a 12- or 24-hour clock
+pub fn format_showtime(time: &DateTime<Local>, use_24h: bool) -> String {
+    let format = if use_24h { "%H:%M" } else { "%-I:%M %p" };
+    time.format(format).to_string()
+}
+
+/// How posters are drawn; anything but auto skips the terminal's own report of what it supports
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageProtocol {
+    #[default]
+    Auto,
+    Halfblocks,
+    Sixel,
+    Kitty,
+    Iterm2,
+}
+
+impl ImageProtocol {
+    /// Parses a value of `CINEMA_TUI_IMAGE_PROTOCOL`, accepting "iterm" for iTerm2
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "auto" => Some(ImageProtocol::Auto),
+            "halfblocks" => Some(ImageProtocol::Halfblocks),
+            "sixel" => Some(ImageProtocol::Sixel),
+            "kitty" => Some(ImageProtocol::Kitty),
+            "iterm" | "iterm2" => Some(ImageProtocol::Iterm2),
+            _ => None,
+        }
+    }
+}
+
+/// How much of the plot OMDb returns
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlotLength {
+    #[default]
+    Short,
+    Full,
+}
+
+impl PlotLength {
+    /// OMDb's value for its `plot` parameter
+    pub fn query_value(&self) -> &'static str {
+        match self {
+            PlotLength::Short => "short",
+            PlotLength::Full => "full",
+        }
+    }
+}
+
+/// Built-in color schemes, picked to suit the terminal's background
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorTheme {
+    #[default]
+    Dark,
+    Light,
+}
+
+/// Colors that replace the theme's own; each takes a ratatui color name or "#rrggbb"
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeColors {
+    pub accent: Option<String>,
+    pub highlight_background: Option<String>,
+    pub header: Option<String>,
+    pub text: Option<String>,
+    pub muted: Option<String>,
+    pub rating_good: Option<String>,
+    pub rating_fair: Option<String>,
+    pub rating_poor: Option<String>,
+}
+
+/// How the week's dates are laid out and stepped through
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateOrder {
+    /// Start at the first upcoming date and wrap around at either end, with `wrap_navigation` on
+    #[default]
+    Ascending,
+    /// Start on today with past dates before it, keeping the selection mid-header and stopping at the ends
+    TodayCentered,
+}
+
+/// When the detail screen switches to its compact single-column layout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompactDetail {
+    /// Only on narrow terminals
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// What Enter does on a movie with exactly one upcoming session
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SingleSessionEnter {
+    /// Open the OMDb detail screen, as for any other movie
+    #[default]
+    Details,
+    /// Open the session's booking page straight away
+    Book,
+    /// Open the booking page and show the detail screen
+    Both,
+}
+
+/// User preferences read from `config.json` in the config dir
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub title_overflow: TitleOverflow,
+    pub list_separator: ListSeparator,
+    pub times_overflow: TimesOverflow,
+    /// Prefix each rating source with an icon (off by default as emoji don't render everywhere)
+    pub rating_icons: bool,
+    /// Listings to hide: plain text matches anywhere in the title, `*`/`?` patterns match the whole title
+    pub ignored_titles: Vec<String>,
+    /// Used when the OMDB_API_KEY environment variable isn't set
+    pub omdb_api_key: Option<String>,
+    /// How long OMDb detail and poster requests may take before giving up
+    pub omdb_timeout_secs: u64,
+    /// Initial window for the "starting soon" view, in hours
+    pub upcoming_window_hours: u32,
+    /// Sessions older than this many days are dropped from the cache
+    pub max_cache_days: u32,
+    /// OMDb details saved to disk are fetched again after this many days
+    pub detail_cache_days: u32,
+    pub date_format: DateFormat,
+    /// Show times on a 24-hour clock, e.g. "19:30" rather than "7:30 PM"
+    pub use_24h: bool,
+    pub date_order: DateOrder,
+    /// Moving past the last movie or date goes back to the first, and the other way round;
+    /// when off the selection stops at the ends
+    pub wrap_navigation: bool,
+    /// Keep cached dates before today in date navigation at startup; (o) still toggles them
+    pub show_past_dates: bool,
+    /// Count feature usage in a local file, viewable with `--stats`
+    pub usage_stats: bool,
+    pub single_session_enter: SingleSessionEnter,
+    /// Show IMDb and Rotten Tomatoes scores as star glyphs next to the number
+    pub rating_stars: bool,
+    /// Fetch OMDb details and posters in the background for the selected movie and its
+    /// neighbours once the selection rests
+    pub preload_details: bool,
+    /// With a minimum rating set, also hide movies whose rating isn't known yet
+    pub min_rating_hides_unrated: bool,
+    /// When the site lists fewer days than this, the rest of the week is filled in with the usual day names
+    pub min_scrape_days: usize,
+    /// Days past the last one the site links to fetch anyway, via their weekday URLs within
+    /// the coming week and dated ones after that
+    pub extra_days: usize,
+    /// Weeks of sessions to fetch. Days past the coming week are asked for by date and left
+    /// out with a warning when the site doesn't list them yet.
+    pub weeks: usize,
+    /// Shortest and longest randomised pause, in milliseconds, before each day page request
+    /// after a worker's first
+    pub scrape_delay_min_ms: u64,
+    pub scrape_delay_max_ms: u64,
+    /// Longest wait between movies for them to count as a double feature
+    pub double_feature_gap_minutes: i64,
+    /// Genres, directors, actors or franchise names whose movies get a marker in the list
+    pub interests: Vec<String>,
+    /// Use blue/orange with symbol cues instead of red/green wherever color carries meaning
+    pub colorblind_palette: bool,
+    pub theme: ColorTheme,
+    pub theme_colors: ThemeColors,
+    /// Download and show posters on the detail screen; off renders the details straight away
+    pub detail_posters: bool,
+    pub compact_detail: CompactDetail,
+    /// Below this many columns or rows only a "terminal too small" message is drawn
+    pub min_terminal_width: u16,
+    pub min_terminal_height: u16,
+    /// How many minutes before a session its reminder goes off
+    pub reminder_lead_minutes: i64,
+    /// Alert when a favorite has a session today starting within this many minutes; 0 turns it off
+    pub favorite_alert_minutes: i64,
+    /// Reopen the movie list on the date, movie and scroll position it was left on
+    pub remember_list_position: bool,
+    /// Refresh in the background on launch when the cached showtimes are stale
+    pub auto_refresh_on_stale: bool,
+    /// Cached showtimes older than this also count as stale for `auto_refresh_on_stale`
+    pub auto_refresh_after_hours: u32,
+    pub cinema_name: String,
+    /// Street address used for calendar exports and the directions link
+    pub cinema_address: String,
+    /// Show the cinema's address at the end of the detail screen
+    pub show_cinema_address: bool,
+    /// Tab/Shift+Tab move focus between the dates, the list and the selected movie's times,
+    /// and only the focused region takes arrow keys
+    pub focus_regions: bool,
+    /// Restrict OMDb title searches to movies, skipping same-named series and games
+    pub omdb_movies_only: bool,
+    /// Titles searched without the movie restriction, for listings OMDb files under another type
+    pub omdb_any_type_titles: Vec<String>,
+    /// How long to wait for the terminal to report image support before falling back to halfblocks
+    pub image_query_timeout_ms: u64,
+    /// Poster protocol to use instead of detecting one; `CINEMA_TUI_IMAGE_PROTOCOL` overrides it
+    pub image_protocol: ImageProtocol,
+    /// Write debug logging to `cinema_tui.log` in the cache dir; `CINEMA_TUI_LOG` picks a level
+    pub debug_log: bool,
+    /// Plot shown on the detail screen; p there switches it
+    pub plot: PlotLength,
+    /// Hour the day starts at, 0-23: sessions before it count toward the previous day and
+    /// "today" only moves on once it passes, so late shows stay with their evening
+    pub day_rollover_hour: u32,
+    /// Date header colors, as names ("cyan", "light_blue") or hex ("#56b4e9")
+    pub today_color: String,
+    pub selected_date_color: String,
+    pub selected_date_background: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            title_overflow: TitleOverflow::default(),
+            list_separator: ListSeparator::default(),
+            times_overflow: TimesOverflow::default(),
+            rating_icons: false,
+            ignored_titles: Vec::new(),
+            omdb_api_key: None,
+            omdb_timeout_secs: 10,
+            upcoming_window_hours: 3,
+            max_cache_days: 14,
+            detail_cache_days: 7,
+            date_format: DateFormat::default(),
+            use_24h: false,
+            date_order: DateOrder::default(),
+            wrap_navigation: true,
+            show_past_dates: false,
+            usage_stats: false,
+            single_session_enter: SingleSessionEnter::default(),
+            rating_stars: false,
+            preload_details: false,
+            min_rating_hides_unrated: false,
+            min_scrape_days: 4,
+            extra_days: 0,
+            weeks: 1,
+            scrape_delay_min_ms: 1000,
+            scrape_delay_max_ms: 2000,
+            double_feature_gap_minutes: 30,
+            interests: Vec::new(),
+            colorblind_palette: false,
+            theme: ColorTheme::default(),
+            theme_colors: ThemeColors::default(),
+            detail_posters: true,
+            compact_detail: CompactDetail::default(),
+            min_terminal_width: 40,
+            min_terminal_height: 12,
+            reminder_lead_minutes: 15,
+            favorite_alert_minutes: 60,
+            remember_list_position: false,
+            auto_refresh_on_stale: false,
+            auto_refresh_after_hours: 24,
+            cinema_name: "Ritz Cinemas".to_string(),
+            cinema_address: "45 St Pauls Street, Randwick NSW 2031".to_string(),
+            show_cinema_address: false,
+            focus_regions: false,
+            omdb_movies_only: true,
+            omdb_any_type_titles: Vec::new(),
+            image_query_timeout_ms: 500,
+            image_protocol: ImageProtocol::default(),
+            debug_log: false,
+            plot: PlotLength::default(),
+            day_rollover_hour: 0,
+            today_color: "cyan".to_string(),
+            selected_date_color: "yellow".to_string(),
+            selected_date_background: "dark_gray".to_string(),
+        }
+    }
+}
+
+impl Config {
+    /// The pause range between day page requests, in milliseconds, however the bounds were set
+    pub fn scrape_delay(&self) -> RangeInclusive<u64> {
+        let (min, max) = (self.scrape_delay_min_ms, self.scrape_delay_max_ms);
+        min.min(max)..=min.max(max)
+    }
+
+    /// Days to fetch past the site's own listing: `extra_days`, or enough to cover `weeks`
+    pub fn scrape_extra_days(&self) -> usize {
+        self.extra_days.max(self.weeks.saturating_sub(1) * 7)
+    }
+
+    /// The day a session time belongs to once `day_rollover_hour` is taken into account.
+    /// The rollover is read off the clock, so a daylight-saving change that night can't
+    /// move a session across it.
+    pub fn movie_night<Tz: TimeZone>(&self, time: DateTime<Tz>) -> NaiveDate {
+        (time.naive_local() - Duration::hours(self.day_rollover_hour.min(23) as i64)).date()
+    }
+
+    /// Today, as far as showtimes are concerned
+    pub fn today(&self) -> NaiveDate {
+        self.movie_night(Local::now())
+    }
+
+    /// The cinema's name and address as one line, e.g. for a calendar event's location
+    pub fn cinema_location(&self) -> String {
+        if self.cinema_address.trim().is_empty() {
+            self.cinema_name.clone()
+        } else {
+            format!("{}, {}", self.cinema_name, self.cinema_address)
+        }
+    }
+
+    /// A maps link giving directions to the cinema from wherever the browser is
+    pub fn directions_url(&self) -> String {
+        format!(
+            "https://www.google.com/maps/dir/?api=1&destination={}",
+            urlencoding::encode(&self.cinema_location())
+        )
+    }
+
+    /// Whether the OMDb search for a scraped title should only consider movies
+    pub fn omdb_movie_search(&self, title: &str) -> bool {
+        self.omdb_movies_only
+            && !self
+                .omdb_any_type_titles
+                .iter()
+                .any(|listed| listed.eq_ignore_ascii_case(title.trim()))
+    }
+
+    /// Whether a scraped title matches any of the ignore patterns (case-insensitive)
+    pub fn is_ignored_title(&self, title: &str) -> bool {
+        let title = title.to_lowercase();
+        self.ignored_titles.iter().any(|pattern| {
+            let pattern = pattern.to_lowercase();
+            if pattern.contains(['*', '?']) {
+                glob_match(&pattern, &title)
+            } else {
+                title.contains(&pattern)
+            }
+        })
+    }
+
+    /// Whether any interest appears in one of `fields` (case-insensitive)
+    pub fn matches_interest(&self, fields: &[&str]) -> bool {
+        self.interests.iter().any(|interest| {
+            let interest = interest.trim().to_lowercase();
+            !interest.is_empty()
+                && fields
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&interest))
+        })
+    }
+}
+
+/// Matches `text` against a glob where `*` is any run of characters and `?` is one character
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` seen and the text index it was tried against
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, star_t)) = backtrack {
+            // Let the last `*` absorb one more character and retry
+            p = star + 1;
+            t = star_t + 1;
+            backtrack = Some((star, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Returns the location of the config file
+pub fn get_config_path() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("cinema_tui");
+    path.push("config.json");
+    path
+}
+
+/// Loads the config file, falling back to defaults when it is missing or corrupt
+pub fn load_config() -> Config {
+    fs::read_to_string(get_config_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+/// Sets one setting in the config file, leaving everything else in it as written. A file
+/// that isn't a JSON object is an error and is left alone rather than replaced
+pub fn save_config_value(key: &str, value: serde_json::Value) -> io::Result<()> {
+    let path = get_config_path();
+    let mut config = match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str::<serde_json::Value>(&contents).map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("config.json is invalid: {}", e))
+        })?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => serde_json::json!({}),
+        Err(e) => return Err(e),
+    };
+    let Some(settings) = config.as_object_mut() else {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "config.json is not a JSON object"));
+    };
+    settings.insert(key.to_string(), value);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(&config).map_err(io::Error::other)?;
+    write_atomic(&path, json)
+}
+
+/// Treats a blank or whitespace-only API key as unset
+pub fn normalize_api_key(key: Option<String>) -> Option<String> {
+    key.map(|key| key.trim().to_string())
+        .filter(|key| !key.is_empty())
+}
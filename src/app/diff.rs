@@ -0,0 +1,60 @@
+use crate::app::MovieTimes;
+
+use chrono::{DateTime, Local};
+
+/// Summary of what changed between two showtime snapshots
+#[derive(Debug, Default)]
+pub struct RefreshDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub new_sessions: Vec<(String, Vec<DateTime<Local>>)>,
+}
+
+impl RefreshDiff {
+    /// Compares the pre-refresh snapshot against freshly fetched data
+    pub fn between(previous: &MovieTimes, current: &MovieTimes) -> Self {
+        let mut added: Vec<String> = current
+            .keys()
+            .filter(|name| !previous.contains_key(*name))
+            .cloned()
+            .collect();
+        added.sort_by_key(|name| name.to_lowercase());
+
+        let mut removed: Vec<String> = previous
+            .keys()
+            .filter(|name| !current.contains_key(*name))
+            .cloned()
+            .collect();
+        removed.sort_by_key(|name| name.to_lowercase());
+
+        let mut new_sessions: Vec<(String, Vec<DateTime<Local>>)> = current
+            .iter()
+            .filter_map(|(name, times)| {
+                let old_times = previous.get(name)?;
+                let mut fresh: Vec<DateTime<Local>> = times
+                    .iter()
+                    .filter(|time| !old_times.contains(time))
+                    .copied()
+                    .collect();
+
+                if fresh.is_empty() {
+                    None
+                } else {
+                    fresh.sort();
+                    Some((name.clone(), fresh))
+                }
+            })
+            .collect();
+        new_sessions.sort_by_key(|(name, _)| name.to_lowercase());
+
+        Self {
+            added,
+            removed,
+            new_sessions,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.new_sessions.is_empty()
+    }
+}
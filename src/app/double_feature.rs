@@ -0,0 +1,63 @@
+use chrono::{DateTime, Duration, Local};
+use std::collections::HashMap;
+
+/// Two sessions on the same day where the second starts soon after the first ends
+pub struct DoubleFeature {
+    pub first: String,
+    pub first_start: DateTime<Local>,
+    pub first_end: DateTime<Local>,
+    pub second: String,
+    pub second_start: DateTime<Local>,
+}
+
+impl DoubleFeature {
+    pub fn gap_minutes(&self) -> i64 {
+        self.second_start
+            .signed_duration_since(self.first_end)
+            .num_minutes()
+    }
+}
+
+/// Pairs sessions of different movies where the second starts within `max_gap_minutes`
+/// of the first ending. Movies without a known runtime can't be paired and are skipped.
+pub fn find_double_features(
+    movies: &[(String, Vec<DateTime<Local>>)],
+    runtimes: &HashMap<String, i64>,
+    max_gap_minutes: i64,
+) -> Vec<DoubleFeature> {
+    let mut pairs = Vec::new();
+
+    for (first, first_times) in movies {
+        let Some(runtime) = runtimes.get(first) else {
+            continue;
+        };
+        for first_start in first_times {
+            let first_end = *first_start + Duration::minutes(*runtime);
+
+            for (second, second_times) in movies {
+                if second == first {
+                    continue;
+                }
+                for second_start in second_times {
+                    let gap = second_start.signed_duration_since(first_end).num_minutes();
+                    if (0..=max_gap_minutes).contains(&gap) {
+                        pairs.push(DoubleFeature {
+                            first: first.clone(),
+                            first_start: *first_start,
+                            first_end,
+                            second: second.clone(),
+                            second_start: *second_start,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    pairs.sort_by(|a, b| {
+        a.first_start
+            .cmp(&b.first_start)
+            .then(a.gap_minutes().cmp(&b.gap_minutes()))
+    });
+    pairs
+}
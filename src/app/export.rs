@@ -0,0 +1,127 @@
+use crate::app::MovieTimes;
+use crate::app::omd::{Welcome, known_value};
+
+use chrono::{DateTime, Local};
+use std::collections::HashMap;
+
+/// Quotes a CSV field when it contains a delimiter, quote or line break (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Every session as a row, in time order then by title
+fn session_rows(movie_times: &MovieTimes) -> Vec<(&str, DateTime<Local>)> {
+    let mut rows: Vec<(&str, DateTime<Local>)> = movie_times
+        .iter()
+        .flat_map(|(name, times)| times.iter().map(move |time| (name.as_str(), *time)))
+        .collect();
+    rows.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+    rows
+}
+
+/// One `movie,date,time` row per session
+pub fn sessions_csv(movie_times: &MovieTimes) -> String {
+    let mut csv = String::from("movie,date,time\n");
+    for (name, time) in session_rows(movie_times) {
+        csv.push_str(&format!(
+            "{},{},{}\n",
+            csv_field(name),
+            time.format("%Y-%m-%d"),
+            time.format("%H:%M")
+        ));
+    }
+    csv
+}
+
+/// Every session as a `{"title", "datetime", "genre", "runtime", "imdb_rating"}` record, with
+/// RFC 3339 datetimes; the detail fields are `null` for uncached movies and fields OMDb
+/// doesn't know
+pub fn sessions_json(movie_times: &MovieTimes, details: &HashMap<String, Welcome>) -> String {
+    let records: Vec<serde_json::Value> = session_rows(movie_times)
+        .into_iter()
+        .map(|(name, time)| {
+            let movie = details.get(name);
+            let field = |value: fn(&Welcome) -> &str| {
+                movie.and_then(|movie| known_value(value(movie)))
+            };
+            serde_json::json!({
+                "title": name,
+                "datetime": time.to_rfc3339(),
+                "genre": field(|movie| &movie.genre),
+                "runtime": field(|movie| &movie.runtime),
+                "imdb_rating": field(|movie| &movie.imdb_rating),
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&records).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Like [`sessions_csv`] with genre, runtime and IMDb rating from any cached OMDb details;
+/// the extra columns are blank for uncached movies and fields OMDb doesn't know
+pub fn sessions_csv_detailed(movie_times: &MovieTimes, details: &HashMap<String, Welcome>) -> String {
+    let mut csv = String::from("movie,date,time,genre,runtime,imdb_rating\n");
+    for (name, time) in session_rows(movie_times) {
+        let movie = details.get(name);
+        let field = |value: fn(&Welcome) -> &str| {
+            movie
+                .and_then(|movie| known_value(value(movie)))
+                .map(csv_field)
+                .unwrap_or_default()
+        };
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            csv_field(name),
+            time.format("%Y-%m-%d"),
+            time.format("%H:%M"),
+            field(|movie| &movie.genre),
+            field(|movie| &movie.runtime),
+            field(|movie| &movie.imdb_rating),
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// One session of a film whose genre and rating OMDb doesn't know
+    fn sample() -> (MovieTimes, HashMap<String, Welcome>) {
+        let time = Local.with_ymd_and_hms(2026, 10, 16, 19, 30, 0).unwrap();
+        let movie_times = MovieTimes::from([("Wicked".to_string(), vec![time])]);
+        let movie = Welcome {
+            runtime: "160 min".to_string(),
+            ..Welcome::unknown("Wicked")
+        };
+        (movie_times, HashMap::from([("Wicked".to_string(), movie)]))
+    }
+
+    #[test]
+    fn csv_leaves_unknown_fields_blank() {
+        let (movie_times, details) = sample();
+        let csv = sessions_csv_detailed(&movie_times, &details);
+
+        assert!(!csv.contains("N/A"));
+        let row = csv.lines().nth(1).unwrap();
+        assert_eq!(row, "Wicked,2026-10-16,19:30,,160 min,");
+    }
+
+    #[test]
+    fn json_writes_unknown_fields_as_null() {
+        let (movie_times, details) = sample();
+        let json = sessions_json(&movie_times, &details);
+
+        assert!(!json.contains("N/A"));
+        let records: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let record = &records[0];
+        assert_eq!(record["title"], "Wicked");
+        assert!(record["genre"].is_null());
+        assert!(record["imdb_rating"].is_null());
+        assert_eq!(record["runtime"], "160 min");
+    }
+}
@@ -0,0 +1,29 @@
+use crate::app::utils::{caching_available, get_cache_dir, write_atomic};
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+pub fn get_favorites_path() -> PathBuf {
+    get_cache_dir().join("favorites.json")
+}
+
+/// Loads starred movie titles, starting empty when the file is missing or corrupt
+pub fn load_favorites() -> HashSet<String> {
+    fs::read_to_string(get_favorites_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_favorites(favorites: &HashSet<String>) {
+    if !caching_available() {
+        return;
+    }
+    // Sorted so the file diffs cleanly between saves
+    let mut titles: Vec<&String> = favorites.iter().collect();
+    titles.sort();
+    if let Ok(json) = serde_json::to_string_pretty(&titles) {
+        write_atomic(&get_favorites_path(), json).ok();
+    }
+}
@@ -0,0 +1,37 @@
+/// Scores how well `query` matches `candidate` as a case-insensitive subsequence, higher
+/// being better, or `None` when some query character can't be found in order.
+/// Consecutive runs and matches at the start of a word score extra.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous_matched = false;
+    let mut previous_char = ' ';
+
+    for c in candidate.chars() {
+        let matches = next < query.len() && c.to_lowercase().eq(query[next].to_lowercase());
+        if matches {
+            score += 1;
+            if previous_matched {
+                score += 3;
+            }
+            if !previous_char.is_alphanumeric() {
+                score += 2;
+            }
+            next += 1;
+        }
+        previous_matched = matches;
+        previous_char = c;
+    }
+
+    // Shorter titles win ties, so "Dune" ranks above "Dune: Part Two" for "dune"
+    (next == query.len()).then(|| score * 100 - candidate.chars().count() as i64)
+}
@@ -0,0 +1,77 @@
+use crate::app::MovieTimes;
+
+use chrono::{DateTime, Local, Timelike};
+
+/// Time-of-day buckets used to group sessions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBucket {
+    Morning,
+    Afternoon,
+    Evening,
+    Late,
+}
+
+impl TimeBucket {
+    pub const ALL: [TimeBucket; 4] = [
+        TimeBucket::Morning,
+        TimeBucket::Afternoon,
+        TimeBucket::Evening,
+        TimeBucket::Late,
+    ];
+
+    /// Maps an hour of the day (0-23) onto its bucket
+    pub fn for_hour(hour: u32) -> Self {
+        match hour {
+            5..=11 => TimeBucket::Morning,
+            12..=16 => TimeBucket::Afternoon,
+            17..=20 => TimeBucket::Evening,
+            _ => TimeBucket::Late,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeBucket::Morning => "Morning",
+            TimeBucket::Afternoon => "Afternoon",
+            TimeBucket::Evening => "Evening",
+            TimeBucket::Late => "Late",
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            TimeBucket::Morning => 0,
+            TimeBucket::Afternoon => 1,
+            TimeBucket::Evening => 2,
+            TimeBucket::Late => 3,
+        }
+    }
+}
+
+/// Session counts per time bucket for a single day
+pub struct HeatmapRow {
+    pub date: DateTime<Local>,
+    pub counts: [usize; 4],
+}
+
+/// Counts every movie's sessions per day and time-of-day bucket
+pub fn build_heatmap(movie_times: &MovieTimes, dates: &[DateTime<Local>]) -> Vec<HeatmapRow> {
+    let mut rows: Vec<HeatmapRow> = dates
+        .iter()
+        .map(|date| HeatmapRow {
+            date: *date,
+            counts: [0; 4],
+        })
+        .collect();
+
+    for time in movie_times.values().flatten() {
+        if let Some(row) = rows
+            .iter_mut()
+            .find(|row| row.date.date_naive() == time.date_naive())
+        {
+            row.counts[TimeBucket::for_hour(time.hour()).index()] += 1;
+        }
+    }
+
+    rows
+}
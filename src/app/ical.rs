@@ -0,0 +1,82 @@
+use chrono::{DateTime, Duration, Local, Utc};
+
+/// Assumed length of a session when OMDb doesn't give a runtime
+const DEFAULT_RUNTIME_MINUTES: i64 = 120;
+
+/// A single screening to be written as a calendar event
+pub struct CalendarSession<'a> {
+    pub title: &'a str,
+    pub start: DateTime<Local>,
+    /// OMDb runtime string such as "148 min"
+    pub runtime: Option<&'a str>,
+    pub location: &'a str,
+    pub url: Option<&'a str>,
+}
+
+/// Parses OMDb's runtime field ("148 min") into minutes
+pub fn parse_runtime_minutes(runtime: &str) -> Option<i64> {
+    runtime
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()
+        .filter(|minutes| *minutes > 0)
+}
+
+/// Escapes text for an iCalendar property value (RFC 5545 §3.3.11)
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn format_utc(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Builds the VEVENT lines for one session, ending after the movie's runtime
+fn event_lines(session: &CalendarSession) -> Vec<String> {
+    let minutes = session
+        .runtime
+        .and_then(parse_runtime_minutes)
+        .unwrap_or(DEFAULT_RUNTIME_MINUTES);
+    let start = session.start.with_timezone(&Utc);
+    let end = start + Duration::minutes(minutes);
+    let uid_title: String = session
+        .title
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect();
+
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}-{}@cinema_tui", start.timestamp(), uid_title),
+        format!("DTSTAMP:{}", format_utc(Utc::now())),
+        format!("DTSTART:{}", format_utc(start)),
+        format!("DTEND:{}", format_utc(end)),
+        format!("SUMMARY:{}", escape_text(session.title)),
+        format!("LOCATION:{}", escape_text(session.location)),
+    ];
+    if let Some(url) = session.url {
+        lines.push(format!("URL:{}", url));
+    }
+    lines.push("END:VEVENT".to_string());
+    lines
+}
+
+/// Renders sessions as a complete iCalendar document
+pub fn calendar(sessions: &[CalendarSession]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//cinema_tui//EN".to_string(),
+    ];
+    for session in sessions {
+        lines.extend(event_lines(session));
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    // iCalendar requires CRLF line endings
+    lines.join("\r\n") + "\r\n"
+}
@@ -0,0 +1,81 @@
+use crate::app::config::Config;
+use crate::app::utils::get_cache_dir;
+
+use chrono::Local;
+use log::{LevelFilter, Log, Metadata, Record};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+pub fn get_log_path() -> PathBuf {
+    get_cache_dir().join("cinema_tui.log")
+}
+
+/// Appends timestamped records to the log file. Nothing goes to stdout or stderr, which
+/// belong to the TUI.
+struct FileLogger {
+    file: Mutex<File>,
+    level: LevelFilter,
+}
+
+impl Log for FileLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        // Dependencies only get a say for warnings and errors, or their debug output drowns ours
+        metadata.level() <= self.level
+            && (metadata.target().starts_with(env!("CARGO_CRATE_NAME"))
+                || metadata.level() <= log::Level::Warn)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(
+                file,
+                "{} {:<5} {}: {}",
+                Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Starts logging to the log file when `CINEMA_TUI_LOG` names a level (e.g. "debug") or the
+/// config's `debug_log` is on. Logging stays off if the file can't be opened.
+pub fn init_logging(config: &Config) {
+    let level = match std::env::var("CINEMA_TUI_LOG") {
+        Ok(value) => LevelFilter::from_str(value.trim()).unwrap_or(LevelFilter::Off),
+        Err(_) if config.debug_log => LevelFilter::Debug,
+        Err(_) => LevelFilter::Off,
+    };
+    if level == LevelFilter::Off {
+        return;
+    }
+
+    let path = get_log_path();
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let Ok(file) = OpenOptions::new().create(true).append(true).open(&path) else {
+        return;
+    };
+    let logger = FileLogger {
+        file: Mutex::new(file),
+        level,
+    };
+    if log::set_boxed_logger(Box::new(logger)).is_ok() {
+        log::set_max_level(level);
+        log::info!("Logging at {} to {}", level, path.display());
+    }
+}
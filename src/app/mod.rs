@@ -1,5 +1,23 @@
+#[allow(clippy::module_inception)]
 pub mod app;
-mod ritz;
+pub mod cinema;
+pub mod config;
+pub mod diff;
+pub mod double_feature;
+pub mod export;
+pub mod favorites;
+pub mod fuzzy;
+pub mod heatmap;
+pub mod net;
+pub mod ical;
+pub mod logging;
+pub mod overview;
+pub mod paths;
+pub mod ritz;
+pub mod state;
+pub mod stats;
+pub mod upcoming;
+pub mod usage;
 mod utils;
 pub mod omd;
 
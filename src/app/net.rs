@@ -0,0 +1,155 @@
+//! Every HTTP request goes through reqwest's blocking client, which panics when it is built,
+//! used or dropped on a thread driving an async runtime. Requests therefore only run on
+//! fresh threads started here, which never carry a runtime context. One client is shared by
+//! all of them, so connections to the same host are pooled; as a static it is never dropped.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{OnceLock, mpsc};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use reqwest::blocking::Client;
+
+/// How long a cinema page request may take, matching reqwest's blocking default
+const PAGE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Sent with every request, as the cinema site can turn away reqwest's default agent
+const USER_AGENT: &str =
+    "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0 Safari/537.36";
+
+/// The shared client, built on first use; only call this inside [`spawn_request`] or
+/// [`run_isolated`]. Requests needing a different timeout set it per request.
+pub fn http_client() -> Result<&'static Client, reqwest::Error> {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    if let Some(client) = CLIENT.get() {
+        return Ok(client);
+    }
+    let client = Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(PAGE_TIMEOUT)
+        .build()?;
+    Ok(CLIENT.get_or_init(|| client))
+}
+
+/// Tries for every request, counting the first
+pub const REQUEST_ATTEMPTS: usize = 3;
+
+/// Wait before the first retry, doubled before each one after
+const FIRST_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Whether a failed request might succeed if sent again
+pub fn is_transient(error: &reqwest::Error) -> bool {
+    error.is_timeout()
+        || error.is_connect()
+        || error.is_request()
+        || error.status().is_some_and(|status| status.is_server_error())
+}
+
+/// Runs `op` up to `attempts` times, backing off 500ms, 1s, 2s... between tries, for as long
+/// as it fails in a way that may be transient. `on_retry` hears about each retry before the
+/// wait; only the last attempt's error is returned.
+pub fn with_retry<T>(
+    attempts: usize,
+    on_retry: &mut dyn FnMut(String),
+    mut op: impl FnMut() -> Result<T, reqwest::Error>,
+) -> Result<T, reqwest::Error> {
+    let mut delay = FIRST_RETRY_DELAY;
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Err(error) if attempt < attempts && is_transient(&error) => {
+                // URLs can carry API keys, so they stay out of the message and the log
+                let error = error.without_url();
+                log::warn!("Attempt {} of {} failed: {}", attempt, attempts, error);
+                on_retry(format!(
+                    "Request failed ({}), retrying in {:.1}s ({}/{})",
+                    error,
+                    delay.as_secs_f32(),
+                    attempt + 1,
+                    attempts
+                ));
+                thread::sleep(delay);
+                delay *= 2;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Fetches a page's HTML, retrying transient failures
+pub fn fetch_html(url: &str, on_retry: &mut dyn FnMut(String)) -> Result<String, reqwest::Error> {
+    let client = http_client()?;
+    with_retry(REQUEST_ATTEMPTS, on_retry, || {
+        log::debug!("GET {}", url);
+        let response = client.get(url).send()?;
+        log::debug!("{} returned {}", url, response.status());
+        // A 5xx is worth retrying, so it fails here rather than being read as an empty page
+        let response = if response.status().is_server_error() {
+            response.error_for_status()?
+        } else {
+            response
+        };
+        response.text()
+    })
+}
+
+/// Fetches a page that may not exist yet, retrying transient failures. `None` when the site
+/// answers with a 4xx or redirects elsewhere, rather than whatever page it served instead
+pub fn fetch_exact_html(
+    url: &str,
+    on_retry: &mut dyn FnMut(String),
+) -> Result<Option<String>, reqwest::Error> {
+    let client = http_client()?;
+    with_retry(REQUEST_ATTEMPTS, on_retry, || {
+        log::debug!("GET {}", url);
+        let response = client.get(url).send()?.error_for_status()?;
+        log::debug!("{} returned {}", url, response.status());
+        if response.url().as_str().trim_end_matches('/') != url.trim_end_matches('/') {
+            log::info!("{} redirected to {}", url, response.url());
+            return Ok(None);
+        }
+        response.text().map(Some)
+    })
+    .or_else(|error| match error.status() {
+        Some(status) if status.is_client_error() => Ok(None),
+        _ => Err(error),
+    })
+}
+
+/// Runs `work` on a new thread, sending whatever `on_panic` makes of the panic message if it
+/// panics, so the receiver hears about the failure rather than only seeing a disconnect
+pub fn spawn_request<T, W, P>(sender: mpsc::Sender<T>, on_panic: P, work: W) -> JoinHandle<()>
+where
+    T: Send + 'static,
+    W: FnOnce(&mpsc::Sender<T>) + Send + 'static,
+    P: FnOnce(String) -> Option<T> + Send + 'static,
+{
+    thread::spawn(move || {
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| work(&sender)))
+            && let Some(message) = on_panic(panic_message(payload.as_ref()))
+        {
+            let _ = sender.send(message);
+        }
+    })
+}
+
+/// Runs `work` on a new thread and waits for it, for callers without an event loop
+pub fn run_isolated<T: Send>(work: impl FnOnce() -> T + Send) -> T {
+    thread::scope(|scope| {
+        scope
+            .spawn(work)
+            .join()
+            .unwrap_or_else(|payload| panic::resume_unwind(payload))
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let reason = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown error");
+    format!("Network request crashed: {}", reason)
+}
@@ -1,6 +1,18 @@
+use crate::app::config::PlotLength;
+use crate::app::net::{REQUEST_ATTEMPTS, with_retry};
+use crate::app::utils::{caching_available, get_cache_dir, write_atomic};
+use chrono::{Datelike, Local};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use image::DynamicImage;
 use ratatui_image::{picker::Picker, protocol::StatefulProtocol};
+use reqwest::StatusCode;
+use reqwest::blocking::{Client, Response};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -37,6 +49,41 @@ pub struct Welcome {
     pub response: String,
 }
 
+#[cfg(test)]
+impl Welcome {
+    /// A response for `title` with every other field "N/A", as OMDb sends for unknown values
+    pub fn unknown(title: &str) -> Self {
+        let na = || "N/A".to_string();
+        Welcome {
+            title: title.to_string(),
+            year: na(),
+            rated: na(),
+            released: na(),
+            runtime: na(),
+            genre: na(),
+            director: na(),
+            writer: na(),
+            actors: na(),
+            plot: na(),
+            language: na(),
+            country: na(),
+            awards: na(),
+            poster: na(),
+            ratings: Vec::new(),
+            metascore: na(),
+            imdb_rating: na(),
+            imdb_votes: na(),
+            imdb_id: na(),
+            welcome_type: "movie".to_string(),
+            dvd: na(),
+            box_office: na(),
+            production: na(),
+            website: na(),
+            response: "True".to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Rating {
@@ -44,47 +91,508 @@ pub struct Rating {
     pub value: String,
 }
 
-/// Fetches movie details from the OMDb API
-pub fn fetch_movie_details(movie_title: &str, api_key: &str) -> Result<Welcome, Box<dyn Error>> {
-    let url = format!(
-        "http://www.omdbapi.com/?apikey={}&t={}",
-        api_key,
-        urlencoding::encode(movie_title)
-    );
+/// Turns a reqwest error into one the detail screen can show, calling out timeouts explicitly
+fn describe_error(error: reqwest::Error) -> Box<dyn Error> {
+    if error.is_timeout() {
+        "Request timed out - check your connection and press Esc to go back".into()
+    } else {
+        error.into()
+    }
+}
 
-    let response = reqwest::blocking::get(&url)?;
-    
-    if !response.status().is_success() {
-        return Err(format!("API request failed with status: {}", response.status()).into());
+/// Sends a GET request, retrying with backoff while it fails in a way that may be transient
+fn get_with_retry(
+    client: &Client,
+    url: &str,
+    timeout: Duration,
+    on_retry: &mut dyn FnMut(String),
+) -> Result<Response, Box<dyn Error>> {
+    with_retry(REQUEST_ATTEMPTS, on_retry, || {
+        let response = client.get(url).timeout(timeout).send()?;
+        if response.status().is_server_error() {
+            response.error_for_status()
+        } else {
+            Ok(response)
+        }
+    })
+    // The URL carries the API key, so a status error only reports the status
+    .map_err(|error| match error.status() {
+        Some(status) => format!("Request failed with status: {}", status).into(),
+        None => describe_error(error),
+    })
+}
+
+/// Screening formats and accessibility tags cinemas add to listings, compared lowercased
+const LISTING_TAGS: &[&str] = &[
+    "2d", "3d", "4dx", "imax", "dolby", "atmos", "hfr", "oc", "cc", "subtitled", "subbed",
+    "open captions", "open captioned", "closed captions", "audio described",
+    "sensory friendly", "re-release", "rerelease", "advance screening", "sneak peek",
+];
+
+fn is_listing_tag(text: &str) -> bool {
+    LISTING_TAGS.contains(&text.trim().to_lowercase().as_str())
+}
+
+/// Strips what cinemas add to a film's title, e.g. "Dune (Subtitled)", "Alien (1979)",
+/// "Oppenheimer - IMAX" and "Avatar 3D", so OMDb's exact-title lookup can match it.
+/// Bracketed groups are dropped wherever they are; dashed suffixes and trailing words only
+/// when they are known tags, so titles like "Mission: Impossible - Fallout" survive.
+pub fn normalize_title(raw: &str) -> String {
+    let mut title = String::with_capacity(raw.len());
+    let mut depth = 0usize;
+    for c in raw.chars() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => title.push(c),
+            _ => {}
+        }
+    }
+    let mut title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    loop {
+        let trimmed = title.trim_end_matches([' ', '-', '–', ':', ',']);
+        let stripped = match trimmed.rsplit_once([' ', '-', '–', ':']) {
+            Some((rest, last)) if is_listing_tag(last) => rest,
+            _ => match trimmed.rsplit_once(" - ") {
+                Some((rest, last)) if is_listing_tag(last) => rest,
+                _ => trimmed,
+            },
+        };
+        if stripped.len() == title.len() || stripped.trim().is_empty() {
+            break;
+        }
+        title = stripped.to_string();
     }
 
-    let movie_data: Welcome = response.json()?;
-    
+    match title.trim_end_matches([' ', '-', '–', ':', ',']) {
+        // Never normalize a title away entirely
+        "" => raw.trim().to_string(),
+        cleaned => cleaned.to_string(),
+    }
+}
+
+/// The failures OMDb reports in its own JSON, which need a different fix from a network error
+#[derive(Debug, Clone, PartialEq)]
+pub enum OmdbError {
+    /// The key is wrong, missing or not yet activated
+    InvalidKey,
+    /// The key's daily request allowance is used up
+    RateLimited,
+    /// Nothing matched the title, which is carried here
+    NotFound(String),
+}
+
+impl fmt::Display for OmdbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OmdbError::InvalidKey => write!(f, "OMDb rejected the API key"),
+            OmdbError::RateLimited => write!(f, "OMDb's daily request limit is reached for this key"),
+            OmdbError::NotFound(title) => write!(f, "Movie not found: {}", title),
+        }
+    }
+}
+
+impl Error for OmdbError {}
+
+/// Reads OMDb's "Error" field for a key or quota problem. Anything else it reports, such as
+/// "Movie not found!", just means no match.
+fn key_error(value: &serde_json::Value) -> Option<OmdbError> {
+    let message = value.get("Error")?.as_str()?.to_lowercase();
+    if message.contains("api key") {
+        Some(OmdbError::InvalidKey)
+    } else if message.contains("limit") {
+        Some(OmdbError::RateLimited)
+    } else {
+        None
+    }
+}
+
+/// Runs one OMDb query, returning `None` when OMDb answers that nothing matched. A rejected
+/// key or exhausted quota fails with an [`OmdbError`].
+fn query_omdb(
+    client: &Client,
+    timeout: Duration,
+    query: &str,
+    api_key: &str,
+    on_retry: &mut dyn FnMut(String),
+) -> Result<Option<serde_json::Value>, Box<dyn Error>> {
+    let url = format!("http://www.omdbapi.com/?apikey={}&{}", api_key, query);
+
+    let response = get_with_retry(client, &url, timeout, on_retry)?;
+    let status = response.status();
+
+    // A bad key comes back as a 401 with the usual JSON error, so that body is still read
+    if !status.is_success() && status != StatusCode::UNAUTHORIZED {
+        return Err(format!("API request failed with status: {}", status).into());
+    }
+
+    // Read as text first: during outages OMDb can serve an HTML page with a 200 status
+    let body = response.text().map_err(describe_error)?;
+    let value: serde_json::Value = serde_json::from_str(&body).map_err(|_| {
+        format!("OMDb returned an unexpected response: {}", body_snippet(&body))
+    })?;
+
+    if let Some(error) = key_error(&value) {
+        log::warn!("OMDb refused a request: {}", error);
+        return Err(error.into());
+    }
+    if !status.is_success() {
+        return Err(format!("API request failed with status: {}", status).into());
+    }
     // Check if the API returned an error (Response field will be "False")
-    if movie_data.response == "False" {
-        return Err(format!("Movie not found: {}", movie_title).into());
+    if value.get("Response").and_then(|response| response.as_str()) == Some("False") {
+        return Ok(None);
     }
+    Ok(Some(value))
+}
 
-    Ok(movie_data)
+/// How to look a movie up, beyond its title
+#[derive(Debug, Clone, Copy)]
+pub struct LookupOptions {
+    /// Limit a title search to movies; an IMDb ID is always looked up as given
+    pub movies_only: bool,
+    pub plot: PlotLength,
+    /// Look for this year's or last year's release when the title matches an older film.
+    /// It costs up to two more requests, so only the detail screen asks for it.
+    pub recent_fallback: bool,
 }
 
-/// Downloads and prepares a movie poster for rendering
-pub fn download_poster(poster_url: &str, picker: &Picker) -> Result<StatefulProtocol, Box<dyn Error>> {
+/// A release year written into a listing, e.g. "Nosferatu (1922)"
+fn listing_year(raw: &str) -> Option<i32> {
+    raw.split(['(', '[', ')', ']'])
+        .map(str::trim)
+        .filter(|part| part.len() == 4 && part.chars().all(|c| c.is_ascii_digit()))
+        .filter_map(|part| part.parse().ok())
+        .find(|year| (1880..=2100).contains(year))
+}
+
+/// The year a film was released, from OMDb's "2022" or "2019–2021" style
+fn release_year(year: &str) -> Option<i32> {
+    year.get(..4)?.parse().ok()
+}
+
+/// Whether a listing matched to a film from `year` might really be a recent film of the same
+/// name: the listing gives no year of its own and the match is older than last year
+pub fn may_be_remake(movie_title: &str, year: &str) -> bool {
+    listing_year(movie_title).is_none()
+        && release_year(year).is_some_and(|year| year < Local::now().year() - 1)
+}
+
+/// Fetches movie details from the OMDb API, by IMDb ID when a manual correction is set
+/// for the title and by title search otherwise.
+///
+/// A title is normalized first, and if the exact-title lookup still misses, OMDb's search
+/// is asked instead and its top result is looked up by IMDb ID. A year written into the
+/// listing narrows the lookup to it. Without one, and with `recent_fallback` set, a match
+/// older than last year is checked against this year's and last year's releases of the same
+/// title, since a cinema is more likely showing a remake than a decades-old film;
+/// re-releases keep the original when no newer film shares the title. Retries are reported
+/// to `on_retry`.
+pub fn fetch_movie_details(
+    client: &Client,
+    timeout: Duration,
+    movie_title: &str,
+    imdb_id: Option<&str>,
+    options: LookupOptions,
+    api_key: &str,
+    on_retry: &mut dyn FnMut(String),
+) -> Result<Welcome, Box<dyn Error>> {
+    let type_filter = if options.movies_only { "&type=movie" } else { "" };
+    let plot = format!("&plot={}", options.plot.query_value());
+    let value = match imdb_id {
+        Some(imdb_id) => {
+            let query = format!("i={}{}", urlencoding::encode(imdb_id), plot);
+            query_omdb(client, timeout, &query, api_key, on_retry)?
+        }
+        None => {
+            let title = normalize_title(movie_title);
+            let by_title = |year: Option<i32>| {
+                let year = year.map(|year| format!("&y={}", year)).unwrap_or_default();
+                format!("t={}{}{}{}", urlencoding::encode(&title), type_filter, year, plot)
+            };
+            let listed_year = listing_year(movie_title);
+            let mut value = query_omdb(client, timeout, &by_title(listed_year), api_key, on_retry)?;
+
+            let matched_year = value.as_ref().and_then(|value| value["Year"].as_str());
+            if options.recent_fallback
+                && matched_year.is_some_and(|year| may_be_remake(movie_title, year))
+            {
+                let this_year = Local::now().year();
+                for year in [this_year, this_year - 1] {
+                    if let Some(recent) =
+                        query_omdb(client, timeout, &by_title(Some(year)), api_key, on_retry)?
+                    {
+                        value = Some(recent);
+                        break;
+                    }
+                }
+            }
+
+            match value {
+                Some(value) => Some(value),
+                None => {
+                    let query = format!("s={}{}", urlencoding::encode(&title), type_filter);
+                    let found = query_omdb(client, timeout, &query, api_key, on_retry)?;
+                    let top_id = found.and_then(|found| {
+                        found["Search"][0]["imdbID"].as_str().map(str::to_string)
+                    });
+                    match top_id {
+                        Some(top_id) => {
+                            let query = format!("i={}{}", urlencoding::encode(&top_id), plot);
+                            query_omdb(client, timeout, &query, api_key, on_retry)?
+                        }
+                        None => None,
+                    }
+                }
+            }
+        }
+    };
+
+    match value {
+        Some(value) => Ok(serde_json::from_value(value)?),
+        None => Err(OmdbError::NotFound(movie_title.to_string()).into()),
+    }
+}
+
+/// Checks the key with a single lookup of a known film, failing with an [`OmdbError`] when
+/// OMDb rejects the key or its quota is used up
+pub fn check_api_key(client: &Client, timeout: Duration, api_key: &str) -> Result<(), Box<dyn Error>> {
+    query_omdb(client, timeout, "i=tt0111161", api_key, &mut |_| {}).map(|_| ())
+}
+
+/// Condenses a response body to a short single line for error messages
+fn body_snippet(body: &str) -> String {
+    const SNIPPET_CHARS: usize = 120;
+
+    let condensed = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    if condensed.is_empty() {
+        return "(empty body)".to_string();
+    }
+    match condensed.char_indices().nth(SNIPPET_CHARS) {
+        Some((end, _)) => format!("{}...", &condensed[..end]),
+        None => condensed,
+    }
+}
+
+/// A field's value with surrounding space trimmed, or `None` when OMDb left it empty or
+/// "N/A". Anything written out of the app should go through this rather than the raw field.
+pub fn known_value(value: &str) -> Option<&str> {
+    let value = value.trim();
+    (!value.is_empty() && value != "N/A").then_some(value)
+}
+
+/// Pulls an IMDb ID ("tt" followed by digits) out of an ID or a pasted IMDb URL
+pub fn parse_imdb_id(input: &str) -> Option<String> {
+    input
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .find(|part| {
+            part.len() > 2
+                && part.starts_with("tt")
+                && part[2..].chars().all(|c| c.is_ascii_digit())
+        })
+        .map(str::to_string)
+}
+
+/// Checks that OMDb's poster field looks like a real image URL before trying to download it
+pub fn is_valid_poster_url(poster_url: &str) -> bool {
+    let Ok(url) = reqwest::Url::parse(poster_url.trim()) else {
+        return false;
+    };
+
+    let has_image_extension = url
+        .path()
+        .rsplit('.')
+        .next()
+        .is_some_and(|ext| {
+            ["jpg", "jpeg", "png", "gif", "webp"].contains(&ext.to_lowercase().as_str())
+        });
+
+    matches!(url.scheme(), "http" | "https") && url.host_str().is_some() && has_image_extension
+}
+
+pub fn get_poster_cache_dir() -> PathBuf {
+    get_cache_dir().join("posters")
+}
+
+/// Where a poster's raw bytes are kept, named by the SHA-256 of its URL
+fn poster_cache_path(poster_url: &str) -> PathBuf {
+    let hash: String = Sha256::digest(poster_url.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    get_poster_cache_dir().join(hash)
+}
+
+/// Whether a poster's bytes are already on disk
+pub fn is_poster_cached(poster_url: &str) -> bool {
+    poster_cache_path(poster_url).is_file()
+}
+
+/// Reads a poster from the cache, or downloads and caches it; with `use_cache` off the
+/// cache is neither read nor written
+fn load_poster_image(
+    client: &Client,
+    timeout: Duration,
+    poster_url: &str,
+    use_cache: bool,
+    on_retry: &mut dyn FnMut(String),
+) -> Result<DynamicImage, Box<dyn Error>> {
+    let cache_path = poster_cache_path(poster_url);
+    if use_cache && let Ok(bytes) = fs::read(&cache_path) {
+        match image::load_from_memory(&bytes) {
+            Ok(dyn_img) => return Ok(dyn_img),
+            // Corrupt, so drop it and download the poster again
+            Err(_) => {
+                fs::remove_file(&cache_path).ok();
+            }
+        }
+    }
+
     // Download the image
-    let response = reqwest::blocking::get(poster_url)?;
-    
+    let response = get_with_retry(client, poster_url, timeout, on_retry)?;
+
     if !response.status().is_success() {
         return Err(format!("Failed to download poster: status {}", response.status()).into());
     }
 
+    // Placeholder pages can still return 200, so insist on an image content type
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("");
+    if !content_type.starts_with("image/") {
+        return Err(format!("Poster URL did not return an image ({})", content_type).into());
+    }
+
     // Get the image bytes
-    let bytes = response.bytes()?;
-    
+    let bytes = response.bytes().map_err(describe_error)?;
+
     // Decode the image
     let dyn_img = image::load_from_memory(&bytes)?;
-    
-    // Create the protocol for rendering
-    let protocol = picker.new_resize_protocol(dyn_img);
-    
-    Ok(protocol)
+
+    // Only bytes that decoded are cached, so a bad download is never reused
+    if use_cache && caching_available() && fs::create_dir_all(get_poster_cache_dir()).is_ok() {
+        write_atomic(&cache_path, &bytes).ok();
+    }
+
+    Ok(dyn_img)
+}
+
+/// Downloads and prepares a movie poster for rendering
+pub fn download_poster(
+    client: &Client,
+    timeout: Duration,
+    poster_url: &str,
+    picker: &Picker,
+    use_cache: bool,
+    on_retry: &mut dyn FnMut(String),
+) -> Result<StatefulProtocol, Box<dyn Error>> {
+    // The resize protocol can't be stored, but a cached image skips the download
+    let dyn_img = load_poster_image(client, timeout, poster_url, use_cache, on_retry)?;
+    Ok(picker.new_resize_protocol(dyn_img))
+}
+
+/// Downloads a poster into the cache without preparing it, so opening its movie later is instant
+pub fn prefetch_poster(
+    client: &Client,
+    timeout: Duration,
+    poster_url: &str,
+) -> Result<(), Box<dyn Error>> {
+    if is_poster_cached(poster_url) {
+        return Ok(());
+    }
+    load_poster_image(client, timeout, poster_url, true, &mut |_| {}).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_value_leaves_out_what_omdb_does_not_know() {
+        assert_eq!(known_value("N/A"), None);
+        assert_eq!(known_value(" N/A "), None);
+        assert_eq!(known_value(""), None);
+        assert_eq!(known_value(" 160 min "), Some("160 min"));
+    }
+
+    #[test]
+    fn normalize_title_strips_cinema_additions() {
+        let cases = [
+            ("Dune: Part Two (Subtitled)", "Dune: Part Two"),
+            ("Oppenheimer - IMAX", "Oppenheimer"),
+            ("Mission: Impossible - Fallout", "Mission: Impossible - Fallout"),
+            ("Alien (1979)", "Alien"),
+            ("Avatar: The Way of Water 3D", "Avatar: The Way of Water"),
+            ("Nosferatu [2024] (Open Captions)", "Nosferatu"),
+            ("  Wicked   -  IMAX 3D ", "Wicked"),
+            ("Spider-Man: Across the Spider-Verse", "Spider-Man: Across the Spider-Verse"),
+            ("Blade Runner 2049", "Blade Runner 2049"),
+            ("The Batman - Open Captions", "The Batman"),
+            ("Dune: Part Two: IMAX", "Dune: Part Two"),
+            ("(Subtitled)", "(Subtitled)"),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(normalize_title(raw), expected, "{:?}", raw);
+        }
+    }
+
+    /// A PNG `width` pixels wide and one high
+    fn png(width: u32) -> Vec<u8> {
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        DynamicImage::new_rgb8(width, 1)
+            .write_to(&mut bytes, image::ImageFormat::Png)
+            .unwrap();
+        bytes.into_inner()
+    }
+
+    /// Serves each of `bodies` as a PNG to one request in turn, returning the base URL
+    fn serve_pngs(bodies: Vec<Vec<u8>>) -> String {
+        use std::io::{BufRead, BufReader, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for (stream, body) in listener.incoming().zip(bodies) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                    line.clear();
+                }
+                let head = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                stream.write_all(head.as_bytes()).unwrap();
+                stream.write_all(&body).unwrap();
+            }
+        });
+        url
+    }
+
+    #[test]
+    fn posters_skip_the_cache_when_it_is_off() {
+        // The first download is cached as one pixel wide, then the poster "changes"
+        let base = serve_pngs(vec![png(1), png(2)]);
+        let poster_url = format!("{}/{}.png", base, std::process::id());
+        let cache_path = poster_cache_path(&poster_url);
+        let client = Client::builder().no_proxy().build().unwrap();
+        let timeout = Duration::from_secs(5);
+
+        load_poster_image(&client, timeout, &poster_url, true, &mut |_| {}).unwrap();
+        let cached = fs::read(&cache_path).ok();
+
+        // Reading the cache would give the old poster, and writing it would replace it
+        let fresh = load_poster_image(&client, timeout, &poster_url, false, &mut |_| {});
+        let after = fs::read(&cache_path).ok();
+        fs::remove_file(&cache_path).ok();
+
+        assert_eq!(fresh.unwrap().width(), 2);
+        assert_eq!(after, cached);
+        if caching_available() {
+            assert_eq!(cached, Some(png(1)));
+        }
+    }
 }
@@ -0,0 +1,76 @@
+use crate::app::MovieTimes;
+
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+
+/// Columns of the full-schedule table, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverviewColumn {
+    Movie,
+    Dates,
+    Sessions,
+}
+
+impl OverviewColumn {
+    pub const ALL: [OverviewColumn; 3] = [
+        OverviewColumn::Movie,
+        OverviewColumn::Dates,
+        OverviewColumn::Sessions,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OverviewColumn::Movie => "Movie",
+            OverviewColumn::Dates => "Dates",
+            OverviewColumn::Sessions => "Sessions",
+        }
+    }
+
+    /// The column to sort by after this one, wrapping around
+    pub fn next(&self) -> Self {
+        match self {
+            OverviewColumn::Movie => OverviewColumn::Dates,
+            OverviewColumn::Dates => OverviewColumn::Sessions,
+            OverviewColumn::Sessions => OverviewColumn::Movie,
+        }
+    }
+}
+
+/// One movie's whole cached schedule, summarised
+pub struct OverviewRow {
+    pub name: String,
+    pub dates: Vec<NaiveDate>,
+    pub sessions: usize,
+}
+
+/// Summarises every cached movie, ignoring the selected date, sorted by `column`
+pub fn build_overview(
+    movie_times: &MovieTimes,
+    column: OverviewColumn,
+    descending: bool,
+) -> Vec<OverviewRow> {
+    let mut rows: Vec<OverviewRow> = movie_times
+        .iter()
+        .map(|(name, times)| {
+            let dates: BTreeSet<NaiveDate> = times.iter().map(|t| t.date_naive()).collect();
+            OverviewRow {
+                name: name.clone(),
+                dates: dates.into_iter().collect(),
+                sessions: times.len(),
+            }
+        })
+        .collect();
+
+    rows.sort_by(|a, b| {
+        // Name breaks ties so equal rows keep a stable order
+        let by_name = a.name.to_lowercase().cmp(&b.name.to_lowercase());
+        let ordering = match column {
+            OverviewColumn::Movie => by_name,
+            OverviewColumn::Dates => a.dates.first().cmp(&b.dates.first()).then(by_name),
+            OverviewColumn::Sessions => a.sessions.cmp(&b.sessions).then(by_name),
+        };
+        if descending { ordering.reverse() } else { ordering }
+    });
+
+    rows
+}
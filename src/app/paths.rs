@@ -0,0 +1,55 @@
+use crate::app::App;
+use crate::app::config::get_config_path;
+use crate::app::favorites::get_favorites_path;
+use crate::app::logging::get_log_path;
+use crate::app::omd::get_poster_cache_dir;
+use crate::app::ritz::get_endpoint_cache_path;
+use crate::app::state::get_state_path;
+use crate::app::usage::get_usage_path;
+use crate::app::utils::{get_cache_dir, get_export_dir};
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Every file location the app reads or writes, resolved exactly as at runtime
+pub fn resolved_paths() -> Vec<(&'static str, PathBuf)> {
+    vec![
+        ("Cache dir", get_cache_dir()),
+        ("Showtime cache", App::get_cache_path()),
+        ("Endpoint cache", get_endpoint_cache_path()),
+        ("Detail cache", App::get_detail_cache_path()),
+        ("Poster cache", get_poster_cache_dir()),
+        ("State file", get_state_path()),
+        ("Favorites", get_favorites_path()),
+        ("Usage stats", get_usage_path()),
+        ("Debug log", get_log_path()),
+        ("Config file", get_config_path()),
+        ("Export dir", get_export_dir()),
+    ]
+}
+
+/// Deletes the showtime, endpoint, detail and poster caches and the favorites file, returning
+/// the labels of those that existed. Files already gone count as cleared.
+pub fn clear_all_caches() -> io::Result<Vec<&'static str>> {
+    let files = [
+        ("Showtime cache", App::get_cache_path()),
+        ("Endpoint cache", get_endpoint_cache_path()),
+        ("Detail cache", App::get_detail_cache_path()),
+        ("Favorites", get_favorites_path()),
+    ];
+    let mut removed = Vec::new();
+    for (label, path) in files {
+        match fs::remove_file(&path) {
+            Ok(()) => removed.push(label),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+    match fs::remove_dir_all(get_poster_cache_dir()) {
+        Ok(()) => removed.push("Poster cache"),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+    Ok(removed)
+}
@@ -1,47 +1,212 @@
-use std::collections::HashMap;
-use std::ops::Add;
-use std::sync::mpsc;
-
-use crate::app::App;
-use crate::app::utils::{fetch_html, get_offset_from_string};
-use crate::app::MovieFetchMessage;
-use chrono::Duration;
-use chrono::{DateTime, Datelike, Local, NaiveTime, Weekday};
-use rand::Rng;
-use scraper::{Html, Selector};
-use std::thread;
-use std::time;
-
-fn parse_showtimes_from_html(html: &str) -> Vec<(String, Vec<String>)> {
+use crate::app::cinema::{CinemaSource, Listing, ScrapeEvent, Showtime};
+use crate::app::net::{fetch_exact_html, fetch_html};
+use crate::app::utils::{caching_available, get_cache_dir, local_midnight, write_atomic};
+use chrono::{DateTime, Datelike, Local, NaiveDate, Weekday};
+use scraper::{ElementRef, Html, Selector};
+use std::fs;
+use std::path::PathBuf;
+
+const RITZ_BASE_URL: &str = "https://www.ritzcinemas.com.au";
+
+/// Ritz Cinemas in Randwick, read from its now-showing pages
+pub struct RitzCinema {
+    /// Whether the day list may be read from and saved to the endpoint cache
+    pub use_cache: bool,
+}
+
+impl CinemaSource for RitzCinema {
+    fn available_days(
+        &self,
+        min_days: usize,
+        extra_days: usize,
+        on_event: &mut dyn FnMut(ScrapeEvent),
+    ) -> Result<Vec<(DateTime<Local>, String)>, String> {
+        get_dates_for_week_result(min_days, extra_days, self.use_cache, on_event)
+            .map_err(|e| e.to_string())
+    }
+
+    fn day_url(&self, day: &str) -> String {
+        format!("{}/now-showing/{}", RITZ_BASE_URL, day)
+    }
+
+    fn fetch_day(&self, day: &str, on_retry: &mut dyn FnMut(String)) -> Result<String, String> {
+        let Some(date) = dated_tag(day) else {
+            return fetch_html(&self.day_url(day), on_retry).map_err(|e| e.to_string());
+        };
+        // The site may not list that far ahead yet, and its fallback page would otherwise
+        // be read as that day's sessions
+        fetch_exact_html(&self.day_url(day), on_retry)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| {
+                format!(
+                    "The cinema site doesn't list sessions for {} yet",
+                    date.format("%a %-d %b")
+                )
+            })
+    }
+
+    fn is_look_ahead(&self, day: &str) -> bool {
+        dated_tag(day).is_some()
+    }
+
+    fn parse_day(&self, html: &str) -> Vec<Listing> {
+        parse_showtimes_from_html(html)
+    }
+}
+
+/// Turns a site-relative href into a full URL
+fn absolute_url(href: &str) -> String {
+    if href.starts_with("http") {
+        href.to_string()
+    } else {
+        format!("{}/{}", RITZ_BASE_URL, href.trim_start_matches('/'))
+    }
+}
+
+/// Finds the booking link for a session, either inside the time element or wrapping it
+fn session_link(time_el: ElementRef, link_sel: &Selector) -> Option<String> {
+    let inner = time_el
+        .select(link_sel)
+        .next()
+        .and_then(|a| a.value().attr("href"));
+    let wrapping = || {
+        time_el
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .find(|el| el.value().name() == "a")
+            .and_then(|a| a.value().attr("href"))
+    };
+    inner.or_else(wrapping).map(absolute_url)
+}
+
+/// Format codes the site adds as class names on a session's time, with how they are shown
+const FORMAT_CLASSES: [(&str, &str); 6] = [
+    ("oc", "OC"),
+    ("cc", "CC"),
+    ("ad", "AD"),
+    ("3d", "3D"),
+    ("subtitled", "Subtitled"),
+    ("subs", "Subtitled"),
+];
+
+/// Splits a session's text into its time and any label printed after it, e.g.
+/// "7:00 pm OC" into "7:00 pm" and "OC"
+fn split_time_label(text: &str) -> (String, Option<String>) {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    // ASCII-only lowering keeps byte offsets valid for slicing `text`
+    let lower = text.to_ascii_lowercase();
+    let end = ["am", "pm"]
+        .iter()
+        .filter_map(|suffix| lower.find(suffix).map(|index| index + suffix.len()))
+        .min();
+    match end {
+        Some(end) if end < text.len() => {
+            let label = text[end..].trim_matches(|c: char| c.is_whitespace() || "-()[]".contains(c));
+            (text[..end].to_string(), (!label.is_empty()).then(|| label.to_string()))
+        }
+        _ => (text, None),
+    }
+}
+
+/// A session's format (open captions, 3D, subtitles...), from a known class name on the time
+/// element, a label after the time, or a format/tag element beside it
+fn session_format(time_el: ElementRef, trailing: Option<String>) -> Option<String> {
+    let from_class = time_el.value().classes().find_map(|class| {
+        FORMAT_CLASSES
+            .iter()
+            .find(|(code, _)| class.eq_ignore_ascii_case(code))
+            .map(|(_, label)| label.to_string())
+    });
+    // Only siblings up to the next session belong to this one
+    let from_sibling = || {
+        time_el
+            .next_siblings()
+            .filter_map(ElementRef::wrap)
+            .take_while(|el| !el.value().classes().any(|class| class == "Time"))
+            .find(|el| {
+                el.value().classes().any(|class| {
+                    ["format", "tag", "attribute", "label"]
+                        .iter()
+                        .any(|name| class.to_lowercase().contains(name))
+                })
+            })
+            .map(|el| el.text().collect::<String>().trim().to_string())
+            .filter(|text| !text.is_empty())
+    };
+    from_class.or(trailing).or_else(from_sibling)
+}
+
+/// Extracts each listed movie with its session times; movies listed without any
+/// sessions (e.g. "coming soon" stacks) are dropped as there is nothing to show for them
+fn parse_showtimes_from_html(html: &str) -> Vec<Listing> {
     let document = Html::parse_document(html);
     let stack_sel = Selector::parse("li.Stack").expect("valid selector");
     let title_sel = Selector::parse("span.Title a").expect("valid selector");
     let time_sel = Selector::parse("span.Time").expect("valid selector");
+    let link_sel = Selector::parse("a[href]").expect("valid selector");
 
-    document
-        .select(&stack_sel)
+    let stacks: Vec<ElementRef> = document.select(&stack_sel).collect();
+    let movies: Vec<Listing> = stacks
+        .iter()
         .filter_map(|el| {
             let title_el = el.select(&title_sel).next()?;
             let movie_name = title_el.text().collect::<String>().trim().to_string();
-            let times: Vec<String> = el
+            // Fall back to the movie's own page when a session has no booking link
+            let movie_url = title_el.value().attr("href").map(absolute_url);
+            let times: Vec<Showtime> = el
                 .select(&time_sel)
-                .map(|t| t.text().collect::<String>().trim().to_string())
-                .filter(|s| !s.is_empty())
+                .map(|t| {
+                    let (time, label) = split_time_label(&t.text().collect::<String>());
+                    Showtime {
+                        time,
+                        format: session_format(t, label),
+                        booking_url: session_link(t, &link_sel).or_else(|| movie_url.clone()),
+                    }
+                })
+                .filter(|showtime| !showtime.time.is_empty())
                 .collect();
-            if movie_name.is_empty() {
+            if movie_name.is_empty() || times.is_empty() {
                 return None;
             }
-            Some((movie_name, times))
+            Some(Listing {
+                title: movie_name,
+                page_url: movie_url,
+                showtimes: times,
+            })
         })
-        .collect()
+        .collect();
+    log::debug!(
+        "{} li.Stack elements matched, {} with a title and sessions",
+        stacks.len(),
+        movies.len()
+    );
+    movies
 }
 
-fn scrape_available_day_endpoints() -> Result<Vec<String>, reqwest::Error> {
-    let html = fetch_html("https://www.ritzcinemas.com.au/now-showing")?;
+/// Warning raised when the now-showing page lacks the day picker, e.g. a cookie wall or redirect
+pub const UNEXPECTED_PAGE_WARNING: &str =
+    "The cinema site returned an unexpected page (cookie wall or redirect?) - showing the usual week instead";
+
+/// What the now-showing page offered
+enum DayListing {
+    Days(Vec<String>),
+    /// The page had no day picker at all, so it isn't the listing we know how to read
+    UnexpectedPage,
+}
+
+fn scrape_available_day_endpoints(
+    on_retry: &mut dyn FnMut(String),
+) -> Result<DayListing, reqwest::Error> {
+    let html = fetch_html(&format!("{}/now-showing", RITZ_BASE_URL), on_retry)?;
     let document = Html::parse_document(&html);
+    let slide_sel = Selector::parse(".swiper-slide").expect("valid selector");
     let link_sel =
         Selector::parse(".swiper-slide a[href*='/now-showing/']").expect("valid selector");
 
+    if document.select(&slide_sel).next().is_none() {
+        return Ok(DayListing::UnexpectedPage);
+    }
+
     let endpoints: Vec<String> = document
         .select(&link_sel)
         .filter_map(|el| {
@@ -57,19 +222,73 @@ fn scrape_available_day_endpoints() -> Result<Vec<String>, reqwest::Error> {
         })
         .collect();
 
-    Ok(endpoints)
+    Ok(DayListing::Days(endpoints))
+}
+
+/// How long discovered day endpoints are reused before re-scraping the now-showing page
+const ENDPOINT_CACHE_TTL_MINUTES: i64 = 60;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CachedEndpoints {
+    endpoints: Vec<String>,
+    fetched_at: DateTime<Local>,
+}
+
+pub fn get_endpoint_cache_path() -> PathBuf {
+    get_cache_dir().join("endpoint_cache.json")
+}
+
+/// Returns the available day endpoints, reusing a recent scrape when `use_cache` allows
+fn available_day_endpoints(
+    use_cache: bool,
+    on_retry: &mut dyn FnMut(String),
+) -> Result<DayListing, reqwest::Error> {
+    if use_cache
+        && let Ok(contents) = fs::read_to_string(get_endpoint_cache_path())
+        && let Ok(cached) = serde_json::from_str::<CachedEndpoints>(&contents)
+    {
+        let now = Local::now();
+        // Tags like "today" are relative, so a cache from another day is never reused
+        let fresh = now.signed_duration_since(cached.fetched_at).num_minutes()
+            < ENDPOINT_CACHE_TTL_MINUTES
+            && cached.fetched_at.date_naive() == now.date_naive();
+        if fresh && !cached.endpoints.is_empty() {
+            return Ok(DayListing::Days(cached.endpoints));
+        }
+    }
+
+    let DayListing::Days(endpoints) = scrape_available_day_endpoints(on_retry)? else {
+        return Ok(DayListing::UnexpectedPage);
+    };
+    if !use_cache || !caching_available() {
+        return Ok(DayListing::Days(endpoints));
+    }
+    let cached = CachedEndpoints {
+        endpoints: endpoints.clone(),
+        fetched_at: Local::now(),
+    };
+    if let Ok(json) = serde_json::to_string_pretty(&cached) {
+        write_atomic(&get_endpoint_cache_path(), json).ok();
+    }
+
+    Ok(DayListing::Days(endpoints))
+}
+
+/// Format of the tags for days past the coming week, which weekday names can't reach
+const DATED_TAG_FORMAT: &str = "%Y-%m-%d";
+
+/// The day a tag like "2026-10-23" names
+fn dated_tag(tag: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(tag, DATED_TAG_FORMAT).ok()
 }
 
 fn calculate_date_from_tag(tag: &str) -> DateTime<Local> {
-    let today = Local::now()
-        .date_naive()
-        .and_time(NaiveTime::MIN)
-        .and_local_timezone(Local)
-        .unwrap();
+    let today = local_midnight(Local::now().date_naive());
 
     match tag {
         "today" => today,
         "tomorrow" => today + chrono::Days::new(1),
+        _ if let Some(date) = dated_tag(tag) => local_midnight(date),
         _ => {
             // Parse weekday name
             let target_weekday = match tag.to_lowercase().as_str() {
@@ -107,133 +326,163 @@ fn calculate_date_from_tag(tag: &str) -> DateTime<Local> {
     }
 }
 
-fn get_dates_for_week() -> Vec<(chrono::DateTime<Local>, String)> {
-    match scrape_available_day_endpoints() {
-        Ok(endpoints) => endpoints
-            .into_iter()
-            .map(|tag| {
-                let date = calculate_date_from_tag(&tag);
-                (date, tag)
-            })
-            .collect(),
-        Err(e) => {
-            eprintln!(
-                "Warning: Failed to scrape endpoints: {}. Using fallback dates.",
-                e
-            );
-            // Fallback to hardcoded week if scraping fails
-            let mut dates = Vec::new();
-            let today = Local::now()
-                .date_naive()
-                .and_time(NaiveTime::MIN)
-                .and_local_timezone(Local)
-                .unwrap();
-
-            dates.push((today, "today".to_string()));
-            dates.push((today + chrono::Days::new(1), "tomorrow".to_string()));
-
-            for day_offset in 2..7 {
-                let date = today + chrono::Days::new(day_offset);
-                let day_name = date.format("%A").to_string().to_lowercase();
-                dates.push((date, day_name));
-            }
-
-            dates
-        }
+/// Ranks tags by how unambiguously they name a day, lowest first: "today"/"tomorrow" mean
+/// exactly one date, a weekday could be this week or next, and unknown tags are guessed as today
+fn tag_specificity(tag: &str) -> u8 {
+    match tag.to_lowercase().as_str() {
+        "today" | "tomorrow" => 0,
+        _ if dated_tag(tag).is_some() => 0,
+        "monday" | "tuesday" | "wednesday" | "thursday" | "friday" | "saturday" | "sunday" => 1,
+        _ => 2,
     }
 }
 
-pub fn get_ritz_movies_threaded(sender: mpsc::Sender<MovieFetchMessage>) {
-    let mut movie_times: HashMap<String, Vec<DateTime<Local>>> = HashMap::new();
-
-    let dates = match get_dates_for_week_result() {
-        Ok(dates) => dates,
-        Err(e) => {
-            let _ = sender.send(MovieFetchMessage::Error(format!("Failed to get dates: {}", e)));
-            return;
-        }
-    };
-
-    for (date, date_label) in dates {
-        let message = format!("Getting movie times for {}", date_label);
-        let _ = sender.send(MovieFetchMessage::Progress(message));
+/// The week as the site normally lists it, used to fill gaps in the scraped endpoints
+fn fallback_week() -> Vec<(DateTime<Local>, String)> {
+    let today = local_midnight(Local::now().date_naive());
+
+    let mut dates = vec![
+        (today, "today".to_string()),
+        (today + chrono::Days::new(1), "tomorrow".to_string()),
+    ];
+    for day_offset in 2..7 {
+        let date = today + chrono::Days::new(day_offset);
+        let day_name = date.format("%A").to_string().to_lowercase();
+        dates.push((date, day_name));
+    }
+    dates
+}
 
-        let url = format!("https://www.ritzcinemas.com.au/now-showing/{}", date_label);
-        let html = match fetch_html(&url) {
-            Ok(html) => html,
-            Err(e) => {
-                let _ = sender.send(MovieFetchMessage::Error(format!("Failed to fetch {}: {}", date_label, e)));
-                return;
-            }
+/// Appends up to `extra_days` days after the last one in `dates` (sorted), addressed by
+/// weekday name while that names the right day, and by date from next week on
+fn extend_dates(dates: &mut Vec<(DateTime<Local>, String)>, extra_days: usize) {
+    let today = calculate_date_from_tag("today");
+    let last = dates.last().map_or(today, |(date, _)| *date);
+
+    for offset in 1..=extra_days as u64 {
+        let date = last + chrono::Days::new(offset);
+        let weekday = date.format("%A").to_string().to_lowercase();
+        // Past six days ahead the weekday wraps back to this week's date
+        let tag = if calculate_date_from_tag(&weekday).date_naive() == date.date_naive() {
+            weekday
+        } else {
+            date.format(DATED_TAG_FORMAT).to_string()
         };
-
-        // need to randomise this so we don't get blocked
-        let mut rng = rand::thread_rng();
-        let sleep_secs = rng.gen_range(1000..=2000);
-        thread::sleep(time::Duration::from_millis(sleep_secs));
-
-        let showtimes = parse_showtimes_from_html(&html);
-
-        for (movie_name, times) in showtimes {
-            for time in times {
-                let offset = get_offset_from_string(&time);
-                let datetime = date.add(Duration::minutes(offset));
-
-                movie_times
-                    .entry(movie_name.clone())
-                    .or_insert(Vec::new())
-                    .push(datetime);
-            }
+        if !dates.iter().any(|(existing, _)| existing.date_naive() == date.date_naive()) {
+            dates.push((date, tag));
         }
     }
-
-    let _ = sender.send(MovieFetchMessage::Complete(movie_times));
 }
 
-fn get_dates_for_week_result() -> Result<Vec<(chrono::DateTime<Local>, String)>, reqwest::Error> {
-    let endpoints = scrape_available_day_endpoints()?;
-    Ok(endpoints
+/// Resolves the days to scrape, topping up with the fallback week when the site lists
+/// fewer than `min_days` (e.g. during a partial outage) and adding any `extra_days`
+fn get_dates_for_week_result(
+    min_days: usize,
+    extra_days: usize,
+    use_cache: bool,
+    on_event: &mut dyn FnMut(ScrapeEvent),
+) -> Result<Vec<(chrono::DateTime<Local>, String)>, reqwest::Error> {
+    let mut on_retry = |message| on_event(ScrapeEvent::Progress(message));
+    let endpoints = match available_day_endpoints(use_cache, &mut on_retry)? {
+        DayListing::Days(endpoints) => endpoints,
+        DayListing::UnexpectedPage => {
+            on_event(ScrapeEvent::Warning(UNEXPECTED_PAGE_WARNING.to_string()));
+            Vec::new()
+        }
+    };
+    let mut dates: Vec<(DateTime<Local>, String)> = endpoints
         .into_iter()
         .map(|tag| {
             let date = calculate_date_from_tag(&tag);
             (date, tag)
         })
-        .collect())
-}
+        .collect();
 
-pub fn get_ritz_movies(app: &mut App) {
-    let mut movie_times: HashMap<String, Vec<DateTime<Local>>> = HashMap::new();
+    // Two tags can land on the same day (e.g. "today" and today's weekday name); scrape it once
+    dates.sort_by_key(|(date, tag)| (date.date_naive(), tag_specificity(tag)));
+    dates.dedup_by_key(|(date, _)| date.date_naive());
 
-    app.loading_movies = true;
+    if dates.len() < min_days {
+        for (date, tag) in fallback_week() {
+            if !dates.iter().any(|(existing, _)| existing.date_naive() == date.date_naive()) {
+                dates.push((date, tag));
+            }
+        }
+        dates.sort_by_key(|(date, _)| *date);
+    }
 
-    for (date, date_label) in get_dates_for_week() {
-        let message = format!("Getting movie times for {}", date_label);
-        app.loading_messages.push(message);
+    extend_dates(&mut dates, extra_days);
 
-        let url = format!("https://www.ritzcinemas.com.au/now-showing/{}", date_label);
-        let html = fetch_html(&url).unwrap();
+    Ok(dates)
+}
 
-        // need to randomise this so we don't get blocked
-        let mut rng = rand::thread_rng();
-        let sleep_secs = rng.gen_range(1000..=2000);
-        thread::sleep(time::Duration::from_millis(sleep_secs));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A day page cut down to its listing: one film with sessions, one "coming soon" stack
+    /// with a title but no times, and one with times but no title
+    const DAY_PAGE: &str = r#"
+        <ul>
+          <li class="Stack">
+            <span class="Title"><a href="/movies/wicked">Wicked</a></span>
+            <span class="Time"><a href="/book/1">10:30 am</a></span>
+            <span class="Time"><a href="/book/2">7:15 pm</a></span>
+          </li>
+          <li class="Stack">
+            <span class="Title"><a href="/movies/coming-soon">Coming Soon</a></span>
+          </li>
+          <li class="Stack">
+            <span class="Time">8:00 pm</span>
+          </li>
+        </ul>
+    "#;
+
+    #[test]
+    fn stacks_without_times_are_skipped() {
+        let listings = parse_showtimes_from_html(DAY_PAGE);
+
+        assert_eq!(listings.len(), 1);
+        let listing = &listings[0];
+        assert_eq!(listing.title, "Wicked");
+        assert_eq!(
+            listing.page_url.as_deref(),
+            Some("https://www.ritzcinemas.com.au/movies/wicked")
+        );
+        let times: Vec<&str> = listing.showtimes.iter().map(|s| s.time.as_str()).collect();
+        assert_eq!(times, ["10:30 am", "7:15 pm"]);
+        assert!(listings.iter().all(|listing| !listing.showtimes.is_empty()));
+    }
 
-        let showtimes = parse_showtimes_from_html(&html);
+    #[test]
+    fn a_page_of_only_empty_stacks_parses_to_nothing() {
+        let html = r#"<li class="Stack"><span class="Title"><a href="/x">Soon</a></span></li>"#;
+        assert!(parse_showtimes_from_html(html).is_empty());
+    }
 
-        for (movie_name, times) in showtimes {
-            for time in times {
-                let offset = get_offset_from_string(&time);
-                let datetime = date.add(Duration::minutes(offset));
+    #[test]
+    fn labels_split_after_non_ascii_text() {
+        // "İ" lowercases to three bytes, which shifted the split point before
+        assert_eq!(
+            split_time_label("İ 7:00 pm OC"),
+            ("İ 7:00 pm".to_string(), Some("OC".to_string()))
+        );
+        assert_eq!(split_time_label("Ⱥ 10:30 am"), ("Ⱥ 10:30 am".to_string(), None));
+    }
 
-                movie_times
-                    .entry(movie_name.clone())
-                    .or_insert(Vec::new())
-                    .push(datetime);
-            }
+    #[test]
+    fn days_past_the_coming_week_are_asked_for_by_date() {
+        let mut dates = fallback_week();
+        extend_dates(&mut dates, 3);
+
+        let today = Local::now().date_naive();
+        let tags: Vec<&str> = dates[7..].iter().map(|(_, tag)| tag.as_str()).collect();
+        let expected: Vec<String> = (7..10)
+            .map(|days| (today + chrono::Days::new(days)).format("%Y-%m-%d").to_string())
+            .collect();
+        assert_eq!(tags, expected);
+        for (date, tag) in &dates {
+            assert_eq!(calculate_date_from_tag(tag).date_naive(), date.date_naive(), "{}", tag);
         }
     }
-
-    app.loading_movies = false;
-    app.loading_messages.clear();
-    app.ritz_movie_times = movie_times;
 }
@@ -0,0 +1,68 @@
+use crate::app::utils::{caching_available, get_cache_dir, write_atomic};
+
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Small bits of UI state that survive restarts
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppState {
+    pub last_viewed_movie: Option<String>,
+    /// Manual OMDb match corrections, from scraped title to IMDb ID
+    pub imdb_overrides: HashMap<String, String>,
+    /// Personal notes, keyed by scraped title
+    pub notes: HashMap<String, String>,
+    /// Reminders for upcoming sessions, soonest to fire first
+    pub reminders: Vec<Reminder>,
+    /// Where the movie list was left on quit, when `remember_list_position` is on
+    pub list_position: Option<ListPosition>,
+}
+
+/// The movie list's date, selected movie and scroll offset
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListPosition {
+    pub date: NaiveDate,
+    pub movie: String,
+    pub offset: usize,
+}
+
+/// A footer alert shown a set number of minutes before a session starts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub movie: String,
+    pub session: DateTime<Local>,
+    pub lead_minutes: i64,
+    /// Set once the reminder has gone off, so it only fires once
+    #[serde(default)]
+    pub fired: bool,
+}
+
+impl Reminder {
+    pub fn fires_at(&self) -> DateTime<Local> {
+        self.session - Duration::minutes(self.lead_minutes)
+    }
+}
+
+pub fn get_state_path() -> PathBuf {
+    get_cache_dir().join("state.json")
+}
+
+/// Loads persisted state, falling back to defaults when missing or corrupt
+pub fn load_state() -> AppState {
+    fs::read_to_string(get_state_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_state(state: &AppState) {
+    if !caching_available() {
+        return;
+    }
+    if let Ok(json) = serde_json::to_string_pretty(state) {
+        write_atomic(&get_state_path(), json).ok();
+    }
+}
@@ -0,0 +1,84 @@
+use crate::app::MovieTimes;
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, Weekday};
+use std::collections::{BTreeSet, HashSet};
+
+/// Totals across every loaded showtime, for the stats screen
+pub struct Stats {
+    pub movies: usize,
+    pub showtimes: usize,
+    /// Days with at least one session
+    pub days: usize,
+    /// Session counts per weekday, Monday first
+    pub weekday_sessions: [usize; 7],
+    /// The session starting earliest in its day, and its movie
+    pub earliest: Option<(String, DateTime<Local>)>,
+    /// The session starting latest in its day, and its movie
+    pub latest: Option<(String, DateTime<Local>)>,
+    /// Movies with sessions on every loaded day
+    pub every_day: usize,
+    /// Movies with sessions on only one day
+    pub one_day: usize,
+}
+
+impl Stats {
+    /// The weekday with the most sessions, first in the week on a tie
+    pub fn busiest_weekday(&self) -> Option<(Weekday, usize)> {
+        let (index, count) = self
+            .weekday_sessions
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, count)| **count)?;
+        (*count > 0).then(|| (weekday(index), *count))
+    }
+}
+
+/// Monday-first weekday for an index into `weekday_sessions`
+pub fn weekday(index: usize) -> Weekday {
+    Weekday::try_from(index as u8).unwrap_or(Weekday::Mon)
+}
+
+/// Counts sessions, days and movies across every loaded showtime
+pub fn compute_stats(movie_times: &MovieTimes) -> Stats {
+    let mut weekday_sessions = [0; 7];
+    let mut days = BTreeSet::new();
+    let mut earliest: Option<(String, DateTime<Local>)> = None;
+    let mut latest: Option<(String, DateTime<Local>)> = None;
+
+    for (name, times) in movie_times {
+        for time in times {
+            weekday_sessions[time.weekday().num_days_from_monday() as usize] += 1;
+            days.insert(time.date_naive());
+            if earliest.as_ref().is_none_or(|(_, first)| time.time() < first.time()) {
+                earliest = Some((name.clone(), *time));
+            }
+            if latest.as_ref().is_none_or(|(_, last)| time.time() > last.time()) {
+                latest = Some((name.clone(), *time));
+            }
+        }
+    }
+
+    let movie_days: Vec<usize> = movie_times
+        .values()
+        .filter(|times| !times.is_empty())
+        .map(|times| {
+            times
+                .iter()
+                .map(DateTime::date_naive)
+                .collect::<HashSet<NaiveDate>>()
+                .len()
+        })
+        .collect();
+
+    Stats {
+        movies: movie_days.len(),
+        showtimes: weekday_sessions.iter().sum(),
+        days: days.len(),
+        weekday_sessions,
+        earliest,
+        latest,
+        every_day: movie_days.iter().filter(|count| **count == days.len()).count(),
+        one_day: movie_days.iter().filter(|count| **count == 1).count(),
+    }
+}
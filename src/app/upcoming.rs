@@ -0,0 +1,36 @@
+use crate::app::MovieTimes;
+
+use chrono::{DateTime, Duration, Local};
+
+/// Smallest and largest window the quick view can be adjusted to, in hours
+pub const MIN_WINDOW_HOURS: u32 = 1;
+pub const MAX_WINDOW_HOURS: u32 = 12;
+
+/// Today's sessions starting between `now` and `window_hours` from now, soonest first
+pub fn sessions_within(
+    movie_times: &MovieTimes,
+    now: DateTime<Local>,
+    window_hours: u32,
+) -> Vec<(String, DateTime<Local>)> {
+    let window_end = now + Duration::hours(window_hours as i64);
+    let mut sessions: Vec<(String, DateTime<Local>)> = movie_times
+        .iter()
+        .flat_map(|(name, times)| times.iter().map(move |time| (name.clone(), *time)))
+        .filter(|(_, time)| {
+            time.date_naive() == now.date_naive() && *time > now && *time <= window_end
+        })
+        .collect();
+
+    sessions.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    sessions
+}
+
+/// Formats the time until a session starts, e.g. "in 1h 05m"
+pub fn format_countdown(now: DateTime<Local>, start: DateTime<Local>) -> String {
+    let minutes = start.signed_duration_since(now).num_minutes().max(0);
+    if minutes >= 60 {
+        format!("in {}h {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("in {}m", minutes)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::app::utils::{caching_available, get_cache_dir, write_atomic};
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Actions counted when usage stats are enabled
+#[derive(Debug, Clone, Copy)]
+pub enum UsageAction {
+    Refresh,
+    Search,
+    DetailOpen,
+    Export,
+    Copy,
+    Book,
+}
+
+impl UsageAction {
+    fn key(&self) -> &'static str {
+        match self {
+            UsageAction::Refresh => "refresh",
+            UsageAction::Search => "search",
+            UsageAction::DetailOpen => "detail_open",
+            UsageAction::Export => "export",
+            UsageAction::Copy => "copy",
+            UsageAction::Book => "book",
+        }
+    }
+}
+
+/// Per-action counters kept on this machine only; nothing is ever sent anywhere
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UsageStats {
+    pub counts: BTreeMap<String, u64>,
+}
+
+impl UsageStats {
+    pub fn record(&mut self, action: UsageAction) {
+        *self.counts.entry(action.key().to_string()).or_default() += 1;
+    }
+}
+
+pub fn get_usage_path() -> PathBuf {
+    get_cache_dir().join("usage.json")
+}
+
+/// Loads usage counters, starting from zero when missing or corrupt
+pub fn load_usage() -> UsageStats {
+    fs::read_to_string(get_usage_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_usage(stats: &UsageStats) {
+    if !caching_available() {
+        return;
+    }
+    if let Ok(json) = serde_json::to_string_pretty(stats) {
+        write_atomic(&get_usage_path(), json).ok();
+    }
+}
+
+/// Formats the counters most-used first for `--stats`
+pub fn format_usage(stats: &UsageStats) -> String {
+    if stats.counts.is_empty() {
+        return "No usage recorded yet (enable \"usage_stats\" in the config)\n".to_string();
+    }
+
+    let mut counts: Vec<(&String, &u64)> = stats.counts.iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+    let mut report = String::from("Feature usage:\n");
+    for (action, count) in counts {
+        report.push_str(&format!("  {:<12} {}\n", action, count));
+    }
+    report
+}
@@ -1,10 +1,143 @@
-use chrono::{NaiveTime, Timelike};
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveTime, TimeZone, Timelike};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
-pub fn fetch_html(url: &str) -> Result<String, reqwest::Error> {
-    reqwest::blocking::get(url)?.text()
+/// Minutes after midnight for a showtime like "7:30 pm", or `None` if it isn't in that form
+pub fn get_offset_from_string(time_string: &str) -> Option<i64> {
+    let time = NaiveTime::parse_from_str(time_string.trim(), "%-I:%M %P").ok()?;
+    Some((time.hour() as i64 * 60) + time.minute() as i64)
 }
 
-pub fn get_offset_from_string(time_string: &str) -> i64 {
-    let time = NaiveTime::parse_from_str(time_string, "%-I:%M %P").unwrap();
-    (time.hour() as i64 * 60) + time.minute() as i64
+/// The first moment of `date` in local time. A daylight-saving change can repeat midnight,
+/// in which case the earlier one is used, or skip it, in which case the day starts at the
+/// first local time that exists.
+pub fn local_midnight(date: NaiveDate) -> DateTime<Local> {
+    midnight_in(date, &Local)
+}
+
+/// The first moment of `date` in `tz`, as `local_midnight` finds it for local time
+pub fn midnight_in<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> DateTime<Tz> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    (0..=8)
+        .find_map(|quarter| {
+            let time = midnight + Duration::minutes(15 * quarter);
+            tz.from_local_datetime(&time).earliest()
+        })
+        .unwrap_or_else(|| tz.from_utc_datetime(&midnight))
+}
+
+/// Names a directory to keep the cache in instead of the platform's cache directory
+const CACHE_DIR_VAR: &str = "CINEMA_TUI_CACHE_DIR";
+
+/// The `CINEMA_TUI_CACHE_DIR` override, when set to something
+fn cache_dir_override() -> Option<PathBuf> {
+    std::env::var_os(CACHE_DIR_VAR)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Creates `path` if needed and checks a file can be written inside it
+fn is_writable_dir(path: &Path) -> bool {
+    if fs::create_dir_all(path).is_err() {
+        return false;
+    }
+    // An existing directory on a read-only filesystem only fails once we write
+    let probe = path.join(".write_test");
+    let writable = fs::write(&probe, b"").is_ok();
+    fs::remove_file(&probe).ok();
+    writable
+}
+
+/// Returns the app's cache directory; check `caching_available` before writing to it.
+/// `CINEMA_TUI_CACHE_DIR` replaces the platform default, unless it can't be written to.
+/// Every cache file lives under here, so the override moves them all.
+pub fn get_cache_dir() -> PathBuf {
+    static CACHE_DIR: OnceLock<PathBuf> = OnceLock::new();
+    CACHE_DIR
+        .get_or_init(|| {
+            if let Some(path) = cache_dir_override() {
+                if is_writable_dir(&path) {
+                    return path;
+                }
+                log::warn!(
+                    "{} {} isn't writable, using the default cache dir",
+                    CACHE_DIR_VAR,
+                    path.display()
+                );
+            }
+            let mut path = dirs::cache_dir().unwrap_or_else(|| PathBuf::from("."));
+            path.push("cinema_tui");
+            path
+        })
+        .clone()
+}
+
+/// Whether the cache directory exists and is writable, checked once per run
+pub fn caching_available() -> bool {
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+    *AVAILABLE.get_or_init(|| {
+        let overridden = cache_dir_override().is_some_and(|path| path == get_cache_dir());
+        if !overridden && dirs::cache_dir().is_none() {
+            return false;
+        }
+        is_writable_dir(&get_cache_dir())
+    })
+}
+
+/// Writes `contents` to a temp file beside `path` and renames it into place, so readers
+/// never see a half-written file even if the app dies mid-write or two writes overlap
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    // Unique per process so concurrent instances don't share a temp file
+    let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()));
+
+    fs::write(&temp_path, contents)?;
+    fs::rename(&temp_path, path).inspect_err(|_| {
+        fs::remove_file(&temp_path).ok();
+    })
+}
+
+/// Where exported files are written: the downloads folder, else the home directory
+pub fn get_export_dir() -> PathBuf {
+    dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Opens `url` in the default browser without blocking or touching the terminal
+pub fn open_in_browser(url: &str) -> io::Result<()> {
+    open::that_detached(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_handles_midnight_and_noon() {
+        assert_eq!(get_offset_from_string("12:00 am"), Some(0));
+        assert_eq!(get_offset_from_string("12:00 pm"), Some(12 * 60));
+        assert_eq!(get_offset_from_string("12:30 am"), Some(30));
+    }
+
+    #[test]
+    fn offset_reads_hours_with_and_without_leading_zero() {
+        assert_eq!(get_offset_from_string("7:30 pm"), Some(19 * 60 + 30));
+        assert_eq!(get_offset_from_string("07:30 pm"), Some(19 * 60 + 30));
+        assert_eq!(get_offset_from_string("9:05 am"), Some(9 * 60 + 5));
+        assert_eq!(get_offset_from_string("11:45 pm"), Some(23 * 60 + 45));
+        assert_eq!(get_offset_from_string("  10:15 am "), Some(10 * 60 + 15));
+    }
+
+    #[test]
+    fn offset_rejects_malformed_times() {
+        for time in ["", "7:30", "19:30", "13:00 pm", "7:60 pm", "7.30 pm", "seven pm", "pm"] {
+            assert_eq!(get_offset_from_string(time), None, "{:?}", time);
+        }
+    }
 }
@@ -0,0 +1,67 @@
+use chrono::Weekday;
+use clap::{Parser, ValueEnum};
+
+/// Formats for `--export`
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ExportFormat {
+    /// movie,date,time for every cached session
+    Csv,
+    /// Adds genre, runtime and IMDb rating columns, blank when no details are cached
+    CsvDetailed,
+    /// An array of {title, datetime} records with RFC 3339 datetimes
+    Json,
+}
+
+/// Browse Ritz Cinemas showtimes in the terminal
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Print where cache, state and config files are stored, then exit
+    #[arg(long)]
+    pub paths: bool,
+
+    /// Print today's showtimes and exit, refreshing first if the cache is empty or stale
+    #[arg(long)]
+    pub now: bool,
+
+    /// Print locally recorded feature usage counts and exit
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Print every cached session as CSV or JSON and exit
+    #[arg(long, value_name = "FORMAT")]
+    pub export: Option<ExportFormat>,
+
+    /// Skip the delay between page requests when scraping (development only, risks being blocked)
+    #[arg(long)]
+    pub fast: bool,
+
+    /// Fetch fresh showtimes on startup instead of showing the cached ones
+    #[arg(long)]
+    pub refresh: bool,
+
+    /// Neither read nor write the showtime, detail, poster and day list caches for this run
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Start on the first date falling on this weekday, e.g. "fri" or "saturday"
+    #[arg(long, value_name = "WEEKDAY", value_parser = parse_weekday)]
+    pub date: Option<Weekday>,
+
+    /// Fetch this many weeks of sessions, refreshing if the cache stops short. Days past the
+    /// coming week are asked for by date and skipped if the site doesn't list them yet
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u8).range(1..=4))]
+    pub weeks: Option<u8>,
+
+    /// OMDb API key to use instead of OMDB_API_KEY or the config file
+    #[arg(long, value_name = "KEY")]
+    pub api_key: Option<String>,
+}
+
+/// Accepts weekday names in full or abbreviated, in any case
+fn parse_weekday(value: &str) -> Result<Weekday, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("\"{}\" isn't a weekday - try e.g. \"fri\" or \"saturday\"", value))
+}
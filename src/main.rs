@@ -1,43 +1,158 @@
 mod app;
 use app::App;
 
+mod cli;
+use cli::Cli;
+
+mod now;
+
 mod ui;
 
 use std::error::Error;
 use std::io;
+use std::panic;
+use std::process;
+use std::thread;
 use std::time::Duration;
 
+use clap::Parser;
 use ratatui::Terminal;
-use ratatui::crossterm::event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode};
+use ratatui::crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers, MouseButton,
+    MouseEvent, MouseEventKind,
+};
+use ratatui::crossterm::cursor::Show;
 use ratatui::crossterm::execute;
 use ratatui::crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
 };
 use ratatui::prelude::{Backend, CrosstermBackend};
 
+use crate::app::config::{SingleSessionEnter, load_config, normalize_api_key};
+use crate::app::logging::init_logging;
+use crate::app::paths::resolved_paths;
+use crate::app::usage::{UsageAction, format_usage, load_usage};
 use crate::app::{CurrentScreen, MovieFetchMessage, MovieDetailMessage, PosterMessage};
 
+/// Restores the terminal when dropped, so leaving the TUI by error or panic undoes raw mode
+/// and the alternate screen just as a normal quit does
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        // Made before switching screens, so a failure from here on is still undone
+        let guard = TerminalGuard;
+        // This is a special case. Normally using stdout is fine
+        execute!(io::stderr(), EnterAlternateScreen, EnableMouseCapture)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore_terminal();
+    }
+}
+
+fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(io::stderr(), LeaveAlternateScreen, DisableMouseCapture, Show);
+}
+
+/// Restores the terminal before the panic message prints, as the message would otherwise go to
+/// the alternate screen and vanish. Panics on request threads are caught and reported in the
+/// TUI, so theirs only go to the log; printing them would garble the screen.
+fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() != Some("main") {
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown error");
+            match info.location() {
+                Some(location) => log::error!("Request thread panicked at {}: {}", location, message),
+                None => log::error!("Request thread panicked: {}", message),
+            }
+            return;
+        }
+        restore_terminal();
+        default_hook(info);
+    }));
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
-    // setup terminal
-    enable_raw_mode()?;
-    let mut stderr = io::stderr(); // This is a special case. Normally using stdout is fine
-    execute!(stderr, EnterAlternateScreen, EnableMouseCapture)?;
+    let cli = Cli::parse();
+    init_logging(&load_config());
+    if cli.paths {
+        for (label, path) in resolved_paths() {
+            println!("{:<16}{}", format!("{}:", label), path.display());
+        }
+        return Ok(());
+    }
+    if cli.stats {
+        print!("{}", format_usage(&load_usage()));
+        return Ok(());
+    }
+    if cli.now {
+        return now::print_today(cli.fast);
+    }
+    if let Some(format) = cli.export {
+        now::print_export(format);
+        return Ok(());
+    }
 
-    let backend = CrosstermBackend::new(stderr);
+    // setup terminal
+    install_panic_hook();
+    let guard = TerminalGuard::enter()?;
+    let backend = CrosstermBackend::new(io::stderr());
     let mut terminal = Terminal::new(backend)?;
 
     // create app and run it
     let mut app = App::new();
-    run_app(&mut terminal, &mut app);
+    app.fast_scrape |= cli.fast;
+    if cli.no_cache {
+        app.disable_cache();
+    }
+    if let Some(key) = normalize_api_key(cli.api_key) {
+        app.omdb_api_key = Some(key);
+    }
+    app.check_api_key();
+    if let Some(weeks) = cli.weeks {
+        app.config.weeks = weeks as usize;
+    }
+    if cli.refresh || (cli.weeks.is_some() && app.is_short_of_weeks()) {
+        app.fetch_movies();
+    } else {
+        app.auto_refresh_if_stale();
+    }
+    if let Some(weekday) = cli.date {
+        app.select_weekday(weekday);
+    }
+    let result = run_app(&mut terminal, &mut app);
+    if app.config.remember_list_position {
+        app.save_list_position();
+    }
 
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    drop(terminal);
+    drop(guard);
+    // The terminal is already back, so waiting on background requests doesn't hold up the screen
+    app.shutdown();
 
+    // Printed after leaving the alternate screen so it stays visible
+    for warning in &app.scrape_warnings {
+        eprintln!("Warning: {}", warning);
+    }
+    if let Some(report) = app.benchmark_report() {
+        eprint!("{}", report);
+    }
+    if let Err(error) = result {
+        eprintln!("Error: {}", error);
+        process::exit(1);
+    }
     Ok(())
 }
 
@@ -54,19 +169,42 @@ fn run_app<B: Backend + 'static>(
                 Ok(MovieFetchMessage::Progress(message)) => {
                     app.loading_messages.push(message);
                 }
-                Ok(MovieFetchMessage::Complete(movie_times)) => {
-                    app.ritz_movie_times = movie_times;
-                    app.last_updated = Some(chrono::Local::now());
-                    app.update_available_dates();
-                    app.save_cache();
+                Ok(MovieFetchMessage::DayProgress { done, total }) => {
+                    app.loading_progress = Some((done, total));
+                }
+                Ok(MovieFetchMessage::Timing(timing)) => {
+                    app.scrape_timings.push(timing);
+                }
+                Ok(MovieFetchMessage::Warning(warning)) => {
+                    app.loading_messages.push(format!("Warning: {}", warning));
+                    app.scrape_warnings.push(warning);
+                }
+                Ok(MovieFetchMessage::Complete(fetched)) => {
+                    app.apply_fetched_showtimes(fetched);
                     app.loading_movies = false;
                     app.loading_messages.clear();
+                    app.loading_progress = None;
+                    app.refreshing_in_background = false;
+                    app.receiver = None;
+                }
+                Ok(MovieFetchMessage::DayComplete(date, fetched)) => {
+                    app.apply_fetched_day(date, fetched);
+                    app.loading_messages.clear();
+                    app.loading_progress = None;
+                    app.refreshing_in_background = false;
                     app.receiver = None;
-                    app.selected_movie_index = 0;
-                    app.list_state.select(Some(0));
                 }
-                Ok(MovieFetchMessage::Error(error)) => {
-                    app.loading_messages.push(format!("Error: {}", error));
+                Ok(MovieFetchMessage::Error(failure)) => {
+                    // The loading screen closes with the failure, so the banner carries the error
+                    if app.refreshing_in_background {
+                        app.set_error(&format!("Background refresh failed: {}", failure.error));
+                        app.show_stale_banner = app.is_update_recommended();
+                        app.refreshing_in_background = false;
+                    } else {
+                        app.set_error(&format!("Couldn't load showtimes: {}", failure.error));
+                    }
+                    app.loading_messages.push(format!("Error: {}", failure.error));
+                    app.partial_scrape = Some(*failure.partial);
                     app.loading_movies = false;
                     app.receiver = None;
                 }
@@ -79,21 +217,33 @@ fn run_app<B: Backend + 'static>(
         // Check for movie detail messages
         if let Some(receiver) = &app.detail_receiver {
             match receiver.try_recv() {
-                Ok(MovieDetailMessage::Complete(details)) => {
-                    // Check if poster is available and fetch it
-                    let poster_url = details.poster.clone();
-                    app.selected_movie_detail = Some(details);
-                    app.loading_movie_detail = false;
+                Ok(
+                    MovieDetailMessage::Complete(generation, ..)
+                    | MovieDetailMessage::Retrying(generation, _)
+                    | MovieDetailMessage::Error(generation, ..),
+                ) if !app.is_current_detail(generation) => {
+                    // Stale result from a fetch that was replaced or backed out of
+                }
+                Ok(MovieDetailMessage::Complete(_, movie, details)) => {
                     app.detail_receiver = None;
-                    
-                    // Fetch poster if URL is valid
-                    if poster_url != "N/A" && !poster_url.is_empty() {
-                        app.fetch_poster(poster_url);
+                    // Never show details under another movie's title
+                    if app.detail_movie.as_deref() == Some(movie.as_str()) {
+                        app.show_movie_detail(*details);
+                    } else {
+                        app.movie_detail_error =
+                            Some("Details arrived for a different movie - press (r) to retry".to_string());
+                        app.loading_movie_detail = false;
+                        app.refreshing_detail = false;
                     }
                 }
-                Ok(MovieDetailMessage::Error(error)) => {
+                Ok(MovieDetailMessage::Retrying(_, message)) => {
+                    app.set_status(&message);
+                }
+                Ok(MovieDetailMessage::Error(_, error, refusal)) => {
                     app.movie_detail_error = Some(error);
+                    app.omdb_error = refusal;
                     app.loading_movie_detail = false;
+                    app.refreshing_detail = false;
                     app.detail_receiver = None;
                 }
                 Err(_) => {
@@ -105,13 +255,20 @@ fn run_app<B: Backend + 'static>(
         // Check for poster messages
         if let Some(receiver) = &app.poster_receiver {
             match receiver.try_recv() {
-                Ok(PosterMessage::Complete(protocol)) => {
+                Ok(
+                    PosterMessage::Complete(generation, _) | PosterMessage::Error(generation, _),
+                ) if !app.is_current_detail(generation) => {
+                    // Poster for a detail screen that has since changed or closed
+                }
+                Ok(PosterMessage::Complete(_, protocol)) => {
                     app.poster_protocol = Some(protocol);
                     app.loading_poster = false;
                     app.poster_receiver = None;
                 }
-                Ok(PosterMessage::Error(_)) => {
-                    // Silent fail - poster is optional
+                Ok(PosterMessage::Error(_, error)) => {
+                    // The poster is optional, so the failure only replaces the placeholder text
+                    log::warn!("Poster download failed: {}", error);
+                    app.poster_error = Some(error);
                     app.loading_poster = false;
                     app.poster_receiver = None;
                 }
@@ -121,100 +278,587 @@ fn run_app<B: Backend + 'static>(
             }
         }
         
+        app.poll_preload();
+        app.poll_key_check();
+        app.preload_selected_detail();
+        app.enrich_ratings();
+        app.check_reminders();
+        app.check_favorite_sessions();
+
         // Poll for events with a timeout to allow UI updates
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == event::KeyEventKind::Release {
-                    // Skip events that are not KeyEventKind::Press
-                    continue;
-                }
-                // Handle search input when searching is active
-                if app.searching {
-                    match key.code {
-                        KeyCode::Char(c) => {
-                            app.search_term.push(c);
-                        }
-                        KeyCode::Backspace => {
-                            app.search_term.pop();
-                        }
-                        KeyCode::Enter => {
-                            // TODO: Implement search functionality
-                            app.searching = false;
-                        }
-                        KeyCode::Esc => {
-                            app.searching = false;
-                            app.search_term.clear();
-                        }
-                        _ => {}
+        let event = if event::poll(Duration::from_millis(100))? {
+            Some(event::read()?)
+        } else {
+            None
+        };
+        if let Some(Event::Mouse(mouse)) = event {
+            handle_mouse(app, mouse);
+        } else if let Some(Event::Key(key)) = event {
+            if key.kind == event::KeyEventKind::Release {
+                // Skip events that are not KeyEventKind::Press
+                continue;
+            }
+            // Ctrl+C always quits, even mid-input, so nobody is trapped behind a prompt
+            if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                return Ok(());
+            }
+            // Any key dismisses the error and favorite banners, and still does its usual job
+            app.last_error = None;
+            app.imminent_alert = None;
+            // The help overlay scrolls with the usual keys, and any other key closes it
+            if app.show_help {
+                match key.code {
+                    KeyCode::Down | KeyCode::Char('j') => app.scroll_help(1),
+                    KeyCode::Up | KeyCode::Char('k') => app.scroll_help(-1),
+                    KeyCode::PageDown => app.page_help(true),
+                    KeyCode::PageUp => app.page_help(false),
+                    _ => app.show_help = false,
+                }
+                continue;
+            }
+            // The quit confirmation sits over every popup and takes keys until answered
+            if app.current_screen == CurrentScreen::Exiting {
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Char('q') => return Ok(()),
+                    KeyCode::Char('n') | KeyCode::Esc => app.cancel_quit(),
+                    _ => {}
+                }
+                continue;
+            }
+            if app.confirm_clear_cache {
+                match key.code {
+                    KeyCode::Char('y') => app.clear_caches(),
+                    KeyCode::Char('n') | KeyCode::Esc => app.confirm_clear_cache = false,
+                    _ => {}
+                }
+                continue;
+            }
+            // Handle search input when searching is active
+            if app.searching {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        app.push_search_char(c);
+                    }
+                    KeyCode::Backspace => {
+                        app.pop_search_char();
                     }
-                    continue;
+                    // The list stays narrowed to the search until it is cleared
+                    KeyCode::Enter => {
+                        app.commit_search();
+                    }
+                    KeyCode::Esc => {
+                        app.discard_search();
+                    }
+                    _ => {}
                 }
+                continue;
+            }
 
-                match app.current_screen {
-                    CurrentScreen::Main => match key.code {
-                        KeyCode::Char('q') => return Ok(()),
-                        KeyCode::Char('m') => {
-                            app.searching = true;
-                        }
-                        KeyCode::Char('g') => {
-                            if !app.loading_movies {
-                                app.fetch_movies();
-                            }
-                        }
-                        KeyCode::Enter => {
-                            // Fetch movie details
-                            if let Some(movie_name) = app.get_selected_movie_name() {
-                                app.current_screen = CurrentScreen::MovieDetail;
-                                app.fetch_movie_detail(movie_name);
+            // Typing a personal note for a movie
+            if app.editing_note.is_some() {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        app.note_input.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        app.note_input.pop();
+                    }
+                    KeyCode::Enter => {
+                        app.save_note();
+                    }
+                    KeyCode::Esc => {
+                        app.cancel_note_edit();
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            // Typing an IMDb ID to correct the detail screen's match
+            if app.correcting_match {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        app.match_input.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        app.match_input.pop();
+                    }
+                    KeyCode::Enter => {
+                        app.apply_match_correction();
+                    }
+                    KeyCode::Esc => {
+                        app.correcting_match = false;
+                        app.match_input.clear();
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            // Typing into the detail screen's find box
+            if app.detail_searching {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        app.detail_search.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        app.detail_search.pop();
+                    }
+                    KeyCode::Enter => {
+                        app.detail_searching = false;
+                    }
+                    KeyCode::Esc => {
+                        app.reset_detail_search();
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            // Typing into the jump-to-movie palette
+            if app.jump_palette {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        app.jump_input.push(c);
+                        app.jump_index = 0;
+                    }
+                    KeyCode::Backspace => {
+                        app.jump_input.pop();
+                        app.jump_index = 0;
+                    }
+                    KeyCode::Down | KeyCode::Tab => {
+                        app.next_jump_match();
+                    }
+                    KeyCode::Up | KeyCode::BackTab => {
+                        app.previous_jump_match();
+                    }
+                    KeyCode::Enter => {
+                        app.jump_to_match();
+                    }
+                    KeyCode::Esc => {
+                        app.jump_palette = false;
+                        app.jump_input.clear();
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            if key.code == KeyCode::Char('?') {
+                app.open_help();
+                continue;
+            }
+            if key.code == KeyCode::Esc && app.loading_movies {
+                app.cancel_fetch();
+                continue;
+            }
+
+            // Choosing a genre to filter the list by
+            if app.genre_popup {
+                match key.code {
+                    KeyCode::Down | KeyCode::Char('j') | KeyCode::Tab => {
+                        app.next_genre();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') | KeyCode::BackTab => {
+                        app.previous_genre();
+                    }
+                    KeyCode::Enter => {
+                        app.apply_genre_selection();
+                    }
+                    KeyCode::Esc => {
+                        app.genre_popup = false;
+                    }
+                    KeyCode::Char('q') => app.confirm_quit(),
+                    _ => {}
+                }
+                continue;
+            }
+
+            // The session popup sits over the list and takes keys until closed
+            if app.session_popup {
+                match key.code {
+                    KeyCode::Char('q') => app.confirm_quit(),
+                    KeyCode::Enter => {
+                        app.book_popup_session();
+                    }
+                    KeyCode::Char('r') => {
+                        app.remind_popup_session();
+                    }
+                    KeyCode::Esc | KeyCode::Char('b') => {
+                        app.session_popup = false;
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            match app.current_screen {
+                CurrentScreen::Main => match key.code {
+                    KeyCode::Char('q') => app.confirm_quit(),
+                    KeyCode::Char('m') => {
+                        app.start_search();
+                        app.track(UsageAction::Search);
+                    }
+                    KeyCode::Char('g') => {
+                        app.fetch_movies();
+                    }
+                    KeyCode::Char('R') => {
+                        app.refresh_selected_date();
+                    }
+                    KeyCode::Char('C') => {
+                        app.confirm_clear_cache = true;
+                    }
+                    KeyCode::Char('D') => {
+                        app.current_screen = CurrentScreen::RefreshDiff;
+                    }
+                    KeyCode::Char('H') => {
+                        app.current_screen = CurrentScreen::Heatmap;
+                    }
+                    KeyCode::Char('T') => {
+                        app.current_screen = CurrentScreen::Overview;
+                    }
+                    KeyCode::Char('U') => {
+                        app.current_screen = CurrentScreen::Upcoming;
+                    }
+                    KeyCode::Char('S') => {
+                        app.current_screen = CurrentScreen::Stats;
+                    }
+                    KeyCode::Char('M') => {
+                        app.current_screen = CurrentScreen::DoubleFeature;
+                    }
+                    KeyCode::Char('A') => {
+                        app.current_screen = CurrentScreen::Reminders;
+                    }
+                    KeyCode::Char(':') => {
+                        app.open_jump_palette();
+                    }
+                    KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        app.open_jump_palette();
+                    }
+                    KeyCode::Char('P') => {
+                        app.toggle_franchise_group();
+                    }
+                    KeyCode::Char('G') => {
+                        app.open_genre_popup();
+                    }
+                    KeyCode::Char(c @ '1'..='9') => {
+                        app.select_date(c as usize - '1' as usize);
+                    }
+                    KeyCode::Char('t') => {
+                        app.jump_to_today();
+                    }
+                    KeyCode::Char('f') => {
+                        app.toggle_favorite();
+                    }
+                    KeyCode::Char('F') => {
+                        app.toggle_favorites_only();
+                    }
+                    KeyCode::Char('+') | KeyCode::Char('=') => {
+                        app.adjust_min_rating(1);
+                    }
+                    KeyCode::Char('-') => {
+                        app.adjust_min_rating(-1);
+                    }
+                    KeyCode::Char('0') => {
+                        app.clear_min_rating();
+                    }
+                    KeyCode::Char('O') => {
+                        app.toggle_24h();
+                    }
+                    KeyCode::Char('s') => {
+                        app.cycle_list_sort();
+                    }
+                    KeyCode::Char('z') => {
+                        app.compact_dates = !app.compact_dates;
+                    }
+                    KeyCode::Char('w') => {
+                        app.week_view = !app.week_view;
+                    }
+                    KeyCode::Char('e') => {
+                        app.export_movie_day_calendar();
+                    }
+                    KeyCode::Char('u') => {
+                        app.toggle_past_showtimes();
+                    }
+                    KeyCode::Char('o') => {
+                        app.toggle_past_dates();
+                    }
+                    KeyCode::Char('c') => {
+                        app.copy_session_text();
+                    }
+                    KeyCode::Char('y') => {
+                        app.copy_day_times();
+                    }
+                    KeyCode::Char('B') => {
+                        app.open_booking_page();
+                    }
+                    KeyCode::Char('X') => {
+                        app.export_sessions_csv();
+                    }
+                    KeyCode::Char('J') => {
+                        app.export_sessions_json();
+                    }
+                    KeyCode::Char('W') => {
+                        app.export_week_calendars();
+                    }
+                    KeyCode::Char('x') => {
+                        app.toggle_expanded_movie();
+                    }
+                    KeyCode::Tab if app.config.focus_regions => {
+                        app.cycle_focus(true);
+                    }
+                    KeyCode::BackTab if app.config.focus_regions => {
+                        app.cycle_focus(false);
+                    }
+                    KeyCode::Tab => {
+                        app.toggle_list_session_focus();
+                    }
+                    KeyCode::Char('n') => {
+                        app.start_note_edit();
+                    }
+                    KeyCode::Enter if app.list_session_focus => {
+                        app.open_session_popup();
+                    }
+                    // Repeated presses would otherwise start another fetch before the first lands
+                    KeyCode::Enter if app.loading_movie_detail => {}
+                    KeyCode::Esc if app.list_session_focus => {
+                        app.list_session_focus = false;
+                    }
+                    KeyCode::Right | KeyCode::Char('l') if app.list_session_focus => {
+                        app.next_list_session();
+                    }
+                    KeyCode::Left | KeyCode::Char('h') if app.list_session_focus => {
+                        app.previous_list_session();
+                    }
+                    KeyCode::Enter => {
+                        if let Some(movie_name) = app.get_selected_movie_name() {
+                            // A lone session is unambiguous, so it can go straight to booking
+                            let sessions = app.get_upcoming_sessions(&movie_name);
+                            let booking_url = match sessions.as_slice() {
+                                [(_, info)] => info.booking_url.clone(),
+                                _ => None,
+                            };
+                            let mode = app.config.single_session_enter;
+
+                            match (mode, booking_url) {
+                                (SingleSessionEnter::Book, Some(url)) => {
+                                    app.track(UsageAction::Book);
+                                    app.open_url(&url);
+                                }
+                                (SingleSessionEnter::Both, Some(url)) => {
+                                    app.track(UsageAction::Book);
+                                    app.open_movie_detail(movie_name);
+                                    app.open_url(&url);
+                                }
+                                (SingleSessionEnter::Details, _) => {
+                                    app.open_movie_detail(movie_name);
+                                }
+                                // No link to book with, so show the details instead
+                                (_, None) => {
+                                    app.open_movie_detail(movie_name);
+                                    if sessions.len() == 1 {
+                                        app.set_status("No booking link was found for this session");
+                                    }
+                                }
                             }
                         }
-                        KeyCode::Down | KeyCode::Char('j') => {
-                            app.next_movie();
-                        }
-                        KeyCode::Up | KeyCode::Char('k') => {
-                            app.previous_movie();
-                        }
-                        KeyCode::Right | KeyCode::Char('l') => {
-                            app.next_date();
-                        }
-                        KeyCode::Left | KeyCode::Char('h') => {
-                            app.previous_date();
-                        }
-                        _ => {}
-                    },
-                    CurrentScreen::Movie => match key.code {
-                        KeyCode::Char('q') => return Ok(()),
-                        _ => {}
-                    },
-                    CurrentScreen::Date => match key.code {
-                        KeyCode::Char('q') => return Ok(()),
-                        _ => {}
-                    },
-                    CurrentScreen::MovieDetail => match key.code {
-                        KeyCode::Char('q') => return Ok(()),
-                        KeyCode::Esc | KeyCode::Char('b') => {
-                            app.current_screen = CurrentScreen::Main;
-                            app.selected_movie_detail = None;
-                            app.movie_detail_error = None;
-                            // Clean up poster state
-                            app.poster_protocol = None;
-                            app.loading_poster = false;
-                            app.poster_receiver = None;
-                        }
-                        _ => {}
-                    },
-                    CurrentScreen::Exiting => match key.code {
-                        KeyCode::Char('y') => {
-                            return Ok(());
-                        }
-                        KeyCode::Char('n') => {
-                            // TODO switch to close modal
-                            app.current_screen = CurrentScreen::Main;
-                        }
-                        _ => {}
-                    },
-                }
+                    }
+                    KeyCode::Char('.') => {
+                        app.reopen_last_viewed();
+                    }
+                    KeyCode::Esc => {
+                        app.show_stale_banner = false;
+                        app.scrape_warnings.clear();
+                        app.clear_search();
+                    }
+                    KeyCode::Down | KeyCode::Char('j') if app.list_takes_keys() => {
+                        app.next_movie();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') if app.list_takes_keys() => {
+                        app.previous_movie();
+                    }
+                    KeyCode::Right | KeyCode::Char('l') if app.dates_take_keys() => {
+                        app.next_date();
+                    }
+                    KeyCode::Left | KeyCode::Char('h') if app.dates_take_keys() => {
+                        app.previous_date();
+                    }
+                    _ => {}
+                },
+                CurrentScreen::MovieDetail => match key.code {
+                    KeyCode::Char('q') => app.confirm_quit(),
+                    KeyCode::Right | KeyCode::Char('l') => {
+                        app.next_detail_session();
+                    }
+                    KeyCode::Left | KeyCode::Char('h') => {
+                        app.previous_detail_session();
+                    }
+                    // Ignored while loading so a repeated Enter from the list can't book by accident
+                    KeyCode::Enter if app.loading_movie_detail => {}
+                    KeyCode::Enter => {
+                        app.book_detail_session();
+                    }
+                    KeyCode::Down | KeyCode::Char('j') if app.detail_session_focus => {
+                        app.next_detail_session();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') if app.detail_session_focus => {
+                        app.previous_detail_session();
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        app.scroll_detail_down();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        app.scroll_detail_up();
+                    }
+                    KeyCode::PageDown => {
+                        app.page_detail_down();
+                    }
+                    KeyCode::PageUp => {
+                        app.page_detail_up();
+                    }
+                    KeyCode::Char('f') => {
+                        app.detail_session_focus = !app.detail_session_focus;
+                    }
+                    KeyCode::Char('e') => {
+                        app.export_detail_session();
+                    }
+                    KeyCode::Char('a') => {
+                        app.remind_detail_session();
+                    }
+                    KeyCode::Char('d') => {
+                        app.open_directions();
+                    }
+                    KeyCode::Char('i') => {
+                        app.copy_imdb_link();
+                    }
+                    KeyCode::Char('o') => {
+                        app.open_imdb_page();
+                    }
+                    KeyCode::Char('c') => {
+                        app.start_match_correction();
+                    }
+                    KeyCode::Char('n') => {
+                        app.start_note_edit();
+                    }
+                    KeyCode::Char('r') => {
+                        app.refresh_movie_detail();
+                    }
+                    KeyCode::Char('p') => {
+                        app.toggle_plot_length();
+                    }
+                    KeyCode::Char(c @ '1'..='9') => {
+                        app.open_similar_movie(c as usize - '1' as usize);
+                    }
+                    KeyCode::Char('O') => {
+                        app.toggle_24h();
+                    }
+                    KeyCode::Char('/') => {
+                        app.track(UsageAction::Search);
+                        app.detail_searching = true;
+                        app.detail_search.clear();
+                    }
+                    KeyCode::Esc | KeyCode::Char('b') => {
+                        app.current_screen = CurrentScreen::Main;
+                        app.close_movie_detail();
+                    }
+                    _ => {}
+                },
+                CurrentScreen::RefreshDiff | CurrentScreen::Heatmap | CurrentScreen::Stats => match key.code {
+                    KeyCode::Char('q') => app.confirm_quit(),
+                    KeyCode::Esc | KeyCode::Char('b') => {
+                        app.current_screen = CurrentScreen::Main;
+                    }
+                    _ => {}
+                },
+                CurrentScreen::DoubleFeature => match key.code {
+                    KeyCode::Char('q') => app.confirm_quit(),
+                    KeyCode::Right | KeyCode::Char('l') => {
+                        app.next_date();
+                    }
+                    KeyCode::Left | KeyCode::Char('h') => {
+                        app.previous_date();
+                    }
+                    KeyCode::Esc | KeyCode::Char('b') => {
+                        app.current_screen = CurrentScreen::Main;
+                    }
+                    _ => {}
+                },
+                CurrentScreen::Upcoming => match key.code {
+                    KeyCode::Char('q') => app.confirm_quit(),
+                    KeyCode::Char('+') | KeyCode::Char('=') => {
+                        app.adjust_upcoming_window(1);
+                    }
+                    KeyCode::Char('-') => {
+                        app.adjust_upcoming_window(-1);
+                    }
+                    KeyCode::Esc | KeyCode::Char('b') => {
+                        app.current_screen = CurrentScreen::Main;
+                    }
+                    _ => {}
+                },
+                CurrentScreen::Overview => match key.code {
+                    KeyCode::Char('q') => app.confirm_quit(),
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        app.next_overview_row();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        app.previous_overview_row();
+                    }
+                    KeyCode::Char('s') => {
+                        app.cycle_overview_sort();
+                    }
+                    KeyCode::Char('r') => {
+                        app.reverse_overview_sort();
+                    }
+                    KeyCode::Esc | KeyCode::Char('b') => {
+                        app.current_screen = CurrentScreen::Main;
+                    }
+                    _ => {}
+                },
+                CurrentScreen::Reminders => match key.code {
+                    KeyCode::Char('q') => app.confirm_quit(),
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        app.next_reminder();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        app.previous_reminder();
+                    }
+                    KeyCode::Char('d') | KeyCode::Delete => {
+                        app.remove_selected_reminder();
+                    }
+                    KeyCode::Esc | KeyCode::Char('b') => {
+                        app.current_screen = CurrentScreen::Main;
+                    }
+                    _ => {}
+                },
+                // Answered above, before any popup could take the key
+                CurrentScreen::Exiting => {}
             }
         }
     }
 }
+
+/// Mouse support on the main list: clicks select dates and movies, the wheel moves the selection
+fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    // Popups and other screens draw over the list, so its click targets would be stale.
+    // An open prompt keeps its input to itself, as it does for keys
+    if app.current_screen != CurrentScreen::Main
+        || app.searching
+        || app.editing_note.is_some()
+        || app.correcting_match
+        || app.confirm_clear_cache
+        || app.session_popup
+        || app.jump_palette
+        || app.genre_popup
+        || app.show_help
+        || app.loading_movies
+    {
+        return;
+    }
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => app.click(mouse.column, mouse.row),
+        MouseEventKind::ScrollDown => app.next_movie(),
+        MouseEventKind::ScrollUp => app.previous_movie(),
+        _ => {}
+    }
+}
@@ -0,0 +1,83 @@
+use crate::app::App;
+use crate::app::export::{sessions_csv, sessions_csv_detailed, sessions_json};
+use crate::cli::ExportFormat;
+use crate::app::net::run_isolated;
+use crate::app::cinema::{FAST_SCRAPE_WARNING, PartialScrape, ScrapeEvent, scrape_movies};
+use crate::app::config::format_showtime;
+
+use ratatui_image::picker::Picker;
+use std::error::Error;
+use std::sync::atomic::AtomicBool;
+
+const BOLD: &str = "\x1b[1m";
+const CYAN: &str = "\x1b[36m";
+const RESET: &str = "\x1b[0m";
+
+/// Wraps `text` in an ANSI style unless colors are disabled
+fn paint(text: &str, style: &str, color: bool) -> String {
+    if color {
+        format!("{}{}{}", style, text, RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Prints today's movies and session times to stdout without starting the TUI
+pub fn print_today(fast: bool) -> Result<(), Box<dyn Error>> {
+    // Querying the terminal for image support is pointless outside the TUI
+    let mut app = App::with_picker(Picker::halfblocks());
+    app.fast_scrape |= fast;
+
+    if app.ritz_movie_times.is_empty() || app.is_update_recommended() {
+        eprintln!("Refreshing showtimes...");
+        if app.fast_scrape {
+            eprintln!("{}", FAST_SCRAPE_WARNING);
+        }
+        let (min_days, extra_days) = (app.config.min_scrape_days, app.config.scrape_extra_days());
+        let (cinema, delay) = (app.cinema.as_ref(), app.scrape_delay());
+        let fetched = run_isolated(|| {
+            let resume = PartialScrape::default();
+            let never_cancelled = AtomicBool::new(false);
+            scrape_movies(cinema, min_days, extra_days, delay, &never_cancelled, resume, |event| {
+                if let ScrapeEvent::Warning(warning) = event {
+                    eprintln!("Warning: {}", warning);
+                }
+            })
+        })
+        .map_err(|failure| failure.error)?;
+        app.apply_fetched_showtimes(fetched);
+    }
+
+    // https://no-color.org: any non-empty value disables color
+    let color = std::env::var("NO_COLOR").map_or(true, |value| value.is_empty());
+
+    if !app.select_today() {
+        println!("No showtimes today");
+        return Ok(());
+    }
+
+    for (name, mut times) in app.get_filtered_movies() {
+        times.sort();
+        let times_display = times
+            .iter()
+            .map(|t| format_showtime(t, app.config.use_24h))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("{}", paint(&name, BOLD, color));
+        println!("  {}", paint(&times_display, CYAN, color));
+    }
+
+    Ok(())
+}
+
+/// Prints the cached sessions as CSV or JSON. OMDb details only live for a TUI session, so the
+/// detailed columns are only filled by the in-app export (X on the main screen).
+pub fn print_export(format: ExportFormat) {
+    let app = App::with_picker(Picker::halfblocks());
+    let csv = match format {
+        ExportFormat::Csv => sessions_csv(&app.ritz_movie_times),
+        ExportFormat::CsvDetailed => sessions_csv_detailed(&app.ritz_movie_times, &app.detail_cache),
+        ExportFormat::Json => format!("{}\n", sessions_json(&app.ritz_movie_times, &app.detail_cache)),
+    };
+    print!("{}", csv);
+}
@@ -0,0 +1,104 @@
+use crate::app::App;
+use super::theme::theme;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Renders a full-width warning that the cached showtimes are out of date
+pub fn render_stale_banner(frame: &mut Frame, app: &App, area: Rect) {
+    let line = Line::from(vec![
+        Span::styled(
+            format!(
+                "⚠ Showtimes are out of date (last updated: {}). ",
+                app.get_last_updated_display()
+            ),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            "Press (g) to refresh or (Esc) to dismiss",
+            Style::default().fg(Color::White),
+        ),
+    ]);
+
+    let banner = Paragraph::new(line).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+
+    frame.render_widget(banner, area);
+}
+
+/// Renders a red strip explaining why the last showtime fetch failed
+pub fn render_error_banner(frame: &mut Frame, error: &str, area: Rect) {
+    let line = Line::from(vec![
+        Span::styled(
+            format!("✖ {}. ", error.trim_end_matches('.')),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Press any key to dismiss", Style::default().fg(Color::White)),
+    ]);
+
+    let banner = Paragraph::new(line).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red)),
+    );
+
+    frame.render_widget(banner, area);
+}
+
+/// Renders the alert for a favorite starting soon
+pub fn render_alert_banner(frame: &mut Frame, app: &App, alert: &str, area: Rect) {
+    let accent = theme(&app.config).accent;
+    let line = Line::from(vec![
+        Span::styled(
+            format!("{}. ", alert),
+            Style::default().fg(accent).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Press any key to dismiss", Style::default().fg(Color::White)),
+    ]);
+
+    let banner = Paragraph::new(line).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(accent)),
+    );
+
+    frame.render_widget(banner, area);
+}
+
+/// Renders the first warning from the last scrape, noting how many more there were
+pub fn render_warning_banner(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(warning) = app.scrape_warnings.first() else {
+        return;
+    };
+    let more = match app.scrape_warnings.len() {
+        1 => String::new(),
+        n => format!(" (+{} more)", n - 1),
+    };
+
+    let line = Line::from(vec![
+        Span::styled(
+            format!("⚠ {}{}. ", warning, more),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("Press (Esc) to dismiss", Style::default().fg(Color::White)),
+    ]);
+
+    let banner = Paragraph::new(line).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+
+    frame.render_widget(banner, area);
+}
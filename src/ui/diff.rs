@@ -0,0 +1,146 @@
+use crate::app::App;
+use crate::app::diff::RefreshDiff;
+use crate::app::config::format_showtime;
+use super::theme::palette;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+
+/// Renders the "what changed since last refresh" screen
+pub fn render_refresh_diff(frame: &mut Frame, app: &App, area: Rect) {
+    let diff = match &app.refresh_diff {
+        Some(diff) if !diff.is_empty() => diff,
+        Some(_) => {
+            render_message(frame, area, "No changes since the previous refresh");
+            return;
+        }
+        None => {
+            render_message(
+                frame,
+                area,
+                "Nothing to compare yet - press (g) on the main screen to refresh",
+            );
+            return;
+        }
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(25), // Added movies
+            Constraint::Percentage(25), // Removed movies
+            Constraint::Percentage(50), // New sessions
+        ])
+        .split(area);
+
+    let palette = palette(&app.config);
+    // Markers keep added and removed apart without relying on color
+    let (added_marker, removed_marker) = if palette.symbols { ("+ ", "- ") } else { ("", "") };
+    render_name_section(frame, chunks[0], "Added", &diff.added, palette.good, added_marker);
+    render_name_section(frame, chunks[1], "Removed", &diff.removed, palette.poor, removed_marker);
+    render_new_sessions(frame, chunks[2], diff, app.config.use_24h);
+}
+
+/// Renders a list of movie names under a titled block
+fn render_name_section(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    names: &[String],
+    color: Color,
+    marker: &str,
+) {
+    let items: Vec<ListItem> = if names.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "None",
+            Style::default().fg(Color::Gray),
+        ))]
+    } else {
+        names
+            .iter()
+            .map(|name| {
+                ListItem::new(Span::styled(
+                    format!("{}{}", marker, name),
+                    Style::default().fg(color),
+                ))
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .title(format!("{} ({})", title, names.len()))
+            .borders(Borders::ALL),
+    );
+
+    frame.render_widget(list, area);
+}
+
+/// Renders movies that kept screening but gained sessions
+fn render_new_sessions(frame: &mut Frame, area: Rect, diff: &RefreshDiff, use_24h: bool) {
+    let items: Vec<ListItem> = if diff.new_sessions.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "None",
+            Style::default().fg(Color::Gray),
+        ))]
+    } else {
+        diff.new_sessions
+            .iter()
+            .map(|(name, times)| {
+                let times_display = times
+                    .iter()
+                    .map(|t| format!("{} {}", t.format("%a"), format_showtime(t, use_24h)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                ListItem::new(vec![
+                    Line::from(Span::styled(
+                        name.clone(),
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    )),
+                    Line::from(Span::styled(
+                        format!("  {}", times_display),
+                        Style::default().fg(Color::Cyan),
+                    )),
+                ])
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .title(format!("New sessions ({})", diff.new_sessions.len()))
+            .borders(Borders::ALL),
+    );
+
+    frame.render_widget(list, area);
+}
+
+/// Renders a centered message when there is nothing to show
+fn render_message(frame: &mut Frame, area: Rect, message: &str) {
+    let text = vec![
+        Line::from(""),
+        Line::from(Span::styled(message, Style::default().fg(Color::Gray))),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Press (Esc) or (b) to go back",
+            Style::default().fg(Color::Gray),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(
+            Block::default()
+                .title("Changes Since Last Refresh")
+                .borders(Borders::ALL),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, area);
+}
@@ -0,0 +1,95 @@
+use crate::app::App;
+use crate::app::double_feature::find_double_features;
+use crate::app::config::format_showtime;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+};
+
+/// Renders back-to-back session pairs for the selected date
+pub fn render_double_features(frame: &mut Frame, app: &App, area: Rect) {
+    let date_label = app
+        .get_selected_date()
+        .map(|date| date.format(app.config.date_format.short()).to_string())
+        .unwrap_or_default();
+    let block = Block::default()
+        .title(format!("Double Features - {}", date_label))
+        .borders(Borders::ALL);
+
+    let movies = app.get_filtered_movies();
+    let runtimes = app.get_cached_runtimes();
+    let unknown = movies
+        .iter()
+        .filter(|(name, _)| !runtimes.contains_key(name))
+        .count();
+    let pairs = find_double_features(&movies, &runtimes, app.config.double_feature_gap_minutes);
+
+    let skipped_note = Line::from(Span::styled(
+        format!(
+            "{} movie{} skipped - runtime unknown until their details are opened",
+            unknown,
+            if unknown == 1 { "" } else { "s" }
+        ),
+        Style::default().fg(Color::Gray),
+    ));
+
+    if pairs.is_empty() {
+        let mut text = vec![Line::from(Span::styled(
+            format!(
+                "No back-to-back sessions within {} minutes",
+                app.config.double_feature_gap_minutes
+            ),
+            Style::default().fg(Color::Gray),
+        ))];
+        if unknown > 0 {
+            text.push(skipped_note);
+        }
+        let paragraph = Paragraph::new(text)
+            .block(block)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let title_style = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD);
+    let mut items: Vec<ListItem> = pairs
+        .iter()
+        .map(|pair| {
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", format_showtime(&pair.first_start, app.config.use_24h)),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(pair.first.clone(), title_style),
+                    Span::styled(
+                        format!(" (ends {})", format_showtime(&pair.first_end, app.config.use_24h)),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]),
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} ", format_showtime(&pair.second_start, app.config.use_24h)),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(pair.second.clone(), title_style),
+                    Span::styled(
+                        format!(" ({} min gap)", pair.gap_minutes()),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                ]),
+                Line::from(""),
+            ])
+        })
+        .collect();
+    if unknown > 0 {
+        items.push(ListItem::new(skipped_note));
+    }
+
+    frame.render_widget(List::new(items).block(block), area);
+}
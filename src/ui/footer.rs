@@ -1,32 +1,63 @@
-use crate::app::{App, CurrentScreen};
+use crate::app::{App, CurrentScreen, Focus};
+use super::keys::{self, hint};
+use super::theme::palette;
 use ratatui::{
     layout::Rect,
-    style::Style,
-    text::Text,
+    style::{Color, Style},
+    text::{Line, Span, Text},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
 /// Returns the appropriate instruction text based on app state
-fn get_instruction_text(app: &App) -> &'static str {
-    if app.searching {
-        "(Enter) to search, (Esc) to cancel, (q) to quit"
+fn get_instruction_text(app: &App) -> String {
+    if app.current_screen == CurrentScreen::Exiting {
+        hint(keys::QUIT_CONFIRM)
+    } else if app.show_help {
+        hint(keys::HELP)
+    } else if app.confirm_clear_cache {
+        hint(keys::CLEAR_CACHE_CONFIRM)
+    } else if app.searching {
+        hint(keys::SEARCH)
+    } else if app.detail_searching {
+        format!("Type to find in details, {}", hint(keys::DETAIL_FIND))
     } else if app.loading_movies {
-        "Loading movies... (q) to quit"
+        format!("Loading movies... {}", hint(keys::LOADING))
+    } else if app.jump_palette {
+        format!("Type to find a movie, {}", hint(keys::JUMP_PALETTE))
+    } else if app.genre_popup {
+        hint(keys::GENRE_POPUP)
+    } else if app.session_popup {
+        hint(keys::SESSION_POPUP)
     } else {
-        match app.current_screen {
+        let bindings = match app.current_screen {
             CurrentScreen::Main => {
                 if app.ritz_movie_times.is_empty() {
-                    "(g) to load movies, (m) to search movies, (q) to quit"
+                    keys::MAIN_EMPTY
+                } else if app.config.focus_regions && app.list_session_focus {
+                    keys::REGION_SESSIONS
+                } else if app.list_session_focus {
+                    keys::MAIN_LIST_SESSIONS
+                } else if app.config.focus_regions && app.focus == Focus::Dates {
+                    keys::REGION_DATES
+                } else if app.config.focus_regions {
+                    keys::REGION_LIST
                 } else {
-                    "(↑↓/jk) scroll, (←→/hl) change date, (Enter) view details, (g) refresh, (q) quit"
+                    keys::MAIN
                 }
             }
-            CurrentScreen::Movie => "(d) to search dates, (q) to quit",
-            CurrentScreen::MovieDetail => "(Esc/b) back, (q) quit",
-            CurrentScreen::Exiting => "(y) to confirm, (n) to cancel",
-            _ => "",
-        }
+            CurrentScreen::MovieDetail if app.detail_session_focus => keys::DETAIL_SESSIONS,
+            CurrentScreen::MovieDetail => keys::DETAIL,
+            CurrentScreen::RefreshDiff | CurrentScreen::Heatmap | CurrentScreen::Stats => {
+                keys::BACK_ONLY
+            },
+            CurrentScreen::DoubleFeature => keys::DOUBLE_FEATURE,
+            CurrentScreen::Upcoming => keys::UPCOMING,
+            CurrentScreen::Reminders => keys::REMINDERS,
+            CurrentScreen::Overview => keys::OVERVIEW,
+            CurrentScreen::Exiting => keys::QUIT_CONFIRM,
+        };
+        hint(bindings)
     }
 }
 
@@ -36,9 +67,65 @@ pub fn render_footer(frame: &mut Frame, app: &App, area: Rect) {
         .borders(Borders::ALL)
         .style(Style::default());
 
-    let instruction_text = get_instruction_text(app);
-    let bottom = Paragraph::new(Text::styled(instruction_text, Style::default()))
+    if let Some(movie) = &app.editing_note {
+        let input = Paragraph::new(Line::from(vec![
+            Span::styled(format!("Note for {}: ", movie), Style::default().fg(Color::Yellow)),
+            Span::raw(app.note_input.clone()),
+            Span::styled(
+                "  (Enter) save, empty to clear, (Esc) cancel",
+                Style::default().fg(Color::Gray),
+            ),
+        ]))
         .block(bottom_block);
+        frame.render_widget(input, area);
+        return;
+    }
+
+    if app.searching {
+        let input = Paragraph::new(Line::from(vec![
+            Span::styled("Search movies: ", Style::default().fg(Color::Yellow)),
+            Span::raw(app.search_term.clone()),
+            Span::styled(
+                format!("  {}", get_instruction_text(app)),
+                Style::default().fg(Color::Gray),
+            ),
+        ]))
+        .block(bottom_block);
+        frame.render_widget(input, area);
+        return;
+    }
+
+    // The footer doubles as the input box while correcting a match, which works on the error screen too
+    if app.correcting_match {
+        let input = Paragraph::new(Line::from(vec![
+            Span::styled("IMDb ID: ", Style::default().fg(Color::Yellow)),
+            Span::raw(app.match_input.clone()),
+            Span::styled(
+                "  (Enter) save, empty to clear, (Esc) cancel",
+                Style::default().fg(Color::Gray),
+            ),
+        ]))
+        .block(bottom_block);
+        frame.render_widget(input, area);
+        return;
+    }
+
+    // A recent status message temporarily replaces the instructions
+    let bottom = match app.get_status() {
+        Some(status) => Paragraph::new(Text::styled(
+            status,
+            Style::default().fg(palette(&app.config).success),
+        )),
+        // The upcoming-only toggle is easy to forget, so say when it's hiding sessions
+        None if app.hide_past_showtimes && app.current_screen == CurrentScreen::Main => {
+            Paragraph::new(Line::from(vec![
+                Span::styled("[upcoming only] ", Style::default().fg(Color::Yellow)),
+                Span::raw(get_instruction_text(app)),
+            ]))
+        }
+        None => Paragraph::new(Text::styled(get_instruction_text(app), Style::default())),
+    }
+    .block(bottom_block);
 
     frame.render_widget(bottom, area);
 }
@@ -0,0 +1,71 @@
+use crate::app::App;
+use super::theme::theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+/// Most rows listed in the popup at once; the list scrolls past this
+const MAX_VISIBLE_ROWS: u16 = 12;
+
+/// Renders the genre filter popup: "All genres" followed by each genre among cached details
+pub fn render_genre_popup(frame: &mut Frame, app: &App, area: Rect) {
+    let genres = app.available_genres();
+
+    let list_height = (genres.len() as u16 + 1).min(MAX_VISIBLE_ROWS);
+    let hint_height = if genres.is_empty() { 2 } else { 0 };
+    let [popup_area] = Layout::vertical([Constraint::Length(list_height + hint_height + 2)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup_area] = Layout::horizontal([Constraint::Length(40)])
+        .flex(Flex::Center)
+        .areas(popup_area);
+
+    let block = Block::default()
+        .title("Filter by genre")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(popup_area);
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(block, popup_area);
+
+    let [list_area, hint_area] =
+        Layout::vertical([Constraint::Length(list_height), Constraint::Min(0)]).areas(inner);
+
+    let items: Vec<ListItem> = std::iter::once("All genres")
+        .chain(genres.iter().map(String::as_str))
+        .map(|genre| {
+            let marker = if app.genre_filter.as_deref() == Some(genre)
+                || (app.genre_filter.is_none() && genre == "All genres")
+            {
+                "● "
+            } else {
+                "  "
+            };
+            ListItem::new(format!("{}{}", marker, genre))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .highlight_style(
+            theme(&app.config).highlight(),
+        )
+        .highlight_symbol("▶ ");
+
+    let mut state = ListState::default().with_selected(Some(app.genre_index));
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    if genres.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "Genres appear once movie details are fetched",
+                Style::default().fg(Color::Gray),
+            ))
+            .wrap(Wrap { trim: true }),
+            hint_area,
+        );
+    }
+}
@@ -1,57 +1,124 @@
 use crate::app::App;
+use super::loading::spinner;
+use super::theme::{date_styles, theme};
+use chrono::Duration;
 use ratatui::{
-    layout::Rect,
-    style::{Color, Modifier, Style},
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
 /// Renders the header/title block at the top of the screen
 pub fn render_header(frame: &mut Frame, app: &App, area: Rect) {
-    let last_updated = app.get_last_updated_display();
+    let mut last_updated = app.get_last_updated_display();
+    if app.refreshing_in_background {
+        last_updated.push_str(&format!(" {} refreshing", spinner(app.animation_frame())));
+    }
     let update_recommended = app.is_update_recommended();
-    
-    // Calculate spacing to push "Last updated" to the right
-    let title_text = "Cinema Showtimes";
-    let update_text = if update_recommended {
-        format!("⚠ Update recommended - Last: {}", last_updated)
+    let theme = theme(&app.config);
+
+    let update_style = if update_recommended {
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD)
     } else {
-        format!("Last updated: {}", last_updated)
+        Style::default().fg(theme.muted)
+    };
+    let title_style = Style::default().fg(theme.header);
+    let warning = if update_recommended { " ⚠" } else { "" };
+    let no_cache = if app.caching_enabled { "" } else { " · not cached" };
+
+    // When the date header is collapsed, the selected date moves up here
+    let compact_date = match app.get_selected_date() {
+        Some(date) if app.compact_dates => format!(" · {}", date.format(app.config.date_format.short())),
+        _ => String::new(),
     };
-    
+    let full_title = format!("Cinema Showtimes{}", compact_date);
+    let short_title = format!("Cinema{}", compact_date);
+
+    // Progressively shorter renderings, widest first, so staleness info survives narrow terminals
+    let variants = [
+        (
+            full_title.as_str(),
+            if update_recommended {
+                format!("⚠ Update recommended - Last: {}{}", last_updated, no_cache)
+            } else {
+                format!("Last updated: {}{}", last_updated, no_cache)
+            },
+        ),
+        (
+            short_title.as_str(),
+            format!("{}{}{}", last_updated, warning, no_cache),
+        ),
+        ("", format!("{}{}", last_updated, warning)),
+    ];
+
     // Calculate padding needed (account for borders)
     let available_width = area.width.saturating_sub(4) as usize; // 2 for borders, 2 for padding
-    let title_len = title_text.len();
-    let update_len = update_text.chars().count(); // Use chars().count() for unicode
-    let total_text_len = title_len + update_len;
-    
-    let line = if total_text_len < available_width {
-        let spacing = available_width - total_text_len;
-        
-        let update_style = if update_recommended {
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD)
-        } else {
-            Style::default().fg(Color::Gray)
-        };
-        
-        Line::from(vec![
-            Span::styled(title_text, Style::default()),
-            Span::raw(" ".repeat(spacing)),
-            Span::styled(update_text, update_style),
-        ])
-    } else {
-        // If not enough space, just show title
-        Line::from(Span::styled(title_text, Style::default()))
-    };
 
-    let title_block = Block::default()
+    let line = variants
+        .iter()
+        .find_map(|(title_text, update_text)| {
+            // Display width, not bytes or chars, so wide and combining characters align correctly
+            let title_len = title_text.width();
+            let update_len = update_text.width();
+            let separator_len = if title_text.is_empty() { 0 } else { 1 };
+            let total_text_len = title_len + separator_len + update_len;
+
+            if total_text_len > available_width {
+                return None;
+            }
+
+            let spacing = available_width - title_len - update_len;
+            Some(Line::from(vec![
+                Span::styled(*title_text, title_style),
+                Span::raw(" ".repeat(spacing)),
+                Span::styled(update_text.clone(), update_style),
+            ]))
+        })
+        // If not even the shortest rendering fits, just show a title
+        .unwrap_or_else(|| Line::from(Span::styled("Cinema", title_style)));
+
+    let mut title_block = Block::default()
         .borders(Borders::ALL)
         .style(Style::default());
+    if let Some(strip) = week_strip(app) {
+        title_block = title_block.title_bottom(strip.alignment(Alignment::Center));
+    }
 
     let title = Paragraph::new(line).block(title_block);
 
     frame.render_widget(title, area);
 }
+
+/// One-letter cells for the seven days from today, dimmed when a day has no sessions and
+/// highlighted when selected, so week context stays visible on every screen
+fn week_strip(app: &App) -> Option<Line<'static>> {
+    if app.available_dates.is_empty() {
+        return None;
+    }
+    let styles = date_styles(&app.config);
+    let theme = theme(&app.config);
+    let today = app.config.today();
+    let selected = app.get_selected_date().map(|date| date.date_naive());
+
+    let mut spans = vec![Span::raw(" ")];
+    for offset in 0..7 {
+        let day = today + Duration::days(offset);
+        let has_sessions = app.available_dates.iter().any(|date| date.date_naive() == day);
+        let style = match (selected == Some(day), day == today) {
+            (true, true) => styles.selected_today,
+            (true, false) => styles.selected,
+            (false, true) if has_sessions => styles.today,
+            _ if has_sessions => Style::default().fg(theme.text),
+            _ => Style::default().fg(theme.muted),
+        };
+        let initial = day.format("%a").to_string().chars().next().unwrap_or(' ');
+        spans.push(Span::styled(initial.to_string(), style));
+        spans.push(Span::raw(" "));
+    }
+    Some(Line::from(spans))
+}
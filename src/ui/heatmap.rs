@@ -0,0 +1,89 @@
+use crate::app::App;
+use crate::app::heatmap::{TimeBucket, build_heatmap};
+use super::theme::palette;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+const CELL_WIDTH: usize = 11;
+
+/// Renders the week's sessions as a day × time-of-day grid
+pub fn render_heatmap(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .title("Session Heatmap")
+        .borders(Borders::ALL);
+
+    let rows = build_heatmap(&app.ritz_movie_times, app.visible_dates());
+    if rows.is_empty() {
+        let paragraph = Paragraph::new(Span::styled(
+            "No showtimes loaded - press (g) on the main screen to load",
+            Style::default().fg(Color::Gray),
+        ))
+        .block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let heat_colors = palette(&app.config).heat;
+    let max_count = rows
+        .iter()
+        .flat_map(|row| row.counts)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    // Column headings
+    let mut header = vec![Span::raw(format!("{:<12}", ""))];
+    for bucket in TimeBucket::ALL {
+        header.push(Span::styled(
+            format!("{:^width$}", bucket.label(), width = CELL_WIDTH),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    let mut lines = vec![Line::from(header), Line::from("")];
+
+    for row in &rows {
+        let mut spans = vec![Span::styled(
+            format!("{:<12}", row.date.format(app.config.date_format.short())),
+            Style::default().fg(Color::White),
+        )];
+
+        for count in row.counts {
+            let level = (count * (heat_colors.len() - 1)).div_ceil(max_count);
+            spans.push(Span::styled(
+                format!("{:^width$}", count, width = CELL_WIDTH),
+                Style::default()
+                    .bg(heat_colors[level])
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        lines.push(Line::from(spans));
+    }
+
+    // Legend
+    lines.push(Line::from(""));
+    let mut legend = vec![Span::styled("Fewer ", Style::default().fg(Color::Gray))];
+    for color in heat_colors {
+        legend.push(Span::styled("   ", Style::default().bg(color)));
+    }
+    legend.push(Span::styled(
+        format!(" More (busiest: {} sessions)", max_count),
+        Style::default().fg(Color::Gray),
+    ));
+    lines.push(Line::from(legend));
+    lines.push(Line::from(Span::styled(
+        "Morning 5am-12pm, Afternoon 12-5pm, Evening 5-9pm, Late 9pm onwards",
+        Style::default().fg(Color::Gray),
+    )));
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -0,0 +1,73 @@
+use crate::app::App;
+use super::keys::help_sections;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Renders every key binding, grouped by screen, in a scrollable box over the current screen
+pub fn render_help(frame: &mut Frame, app: &mut App, area: Rect) {
+    let sections = help_sections(app.config.focus_regions);
+    let key_width = sections
+        .iter()
+        .flat_map(|(_, bindings)| bindings.iter())
+        .map(|binding| binding.keys.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut lines = Vec::new();
+    for (index, (heading, bindings)) in sections.iter().enumerate() {
+        if index > 0 {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(Span::styled(
+            *heading,
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )));
+        for binding in bindings.iter() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<width$}  ", binding.keys, width = key_width),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(binding.action),
+            ]));
+        }
+    }
+
+    let [popup_area] = Layout::vertical([Constraint::Length(lines.len() as u16 + 2)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup_area] = Layout::horizontal([Constraint::Length(key_width as u16 + 40)])
+        .flex(Flex::Center)
+        .areas(popup_area);
+
+    // The last page starts where the final line reaches the bottom of the box
+    let height = popup_area.height.saturating_sub(2) as usize;
+    app.help_page_height = height;
+    app.help_scroll = app.help_scroll.min(lines.len().saturating_sub(height));
+
+    let mut block = Block::default()
+        .title("Keybindings")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    if lines.len() > height {
+        block = block.title_bottom(format!(
+            " {}-{} of {} (↑↓/PgUp/PgDn) ",
+            app.help_scroll + 1,
+            (app.help_scroll + height).min(lines.len()),
+            lines.len()
+        ));
+    }
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .scroll((app.help_scroll as u16, 0)),
+        popup_area,
+    );
+}
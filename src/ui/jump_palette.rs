@@ -0,0 +1,93 @@
+use crate::app::App;
+use crate::app::config::format_showtime;
+use super::text::truncate_to_width;
+use super::theme::theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// Most matches listed in the palette at once
+const MAX_VISIBLE_MATCHES: u16 = 10;
+
+/// Renders the jump-to-movie palette: an input line above the best fuzzy matches
+pub fn render_jump_palette(frame: &mut Frame, app: &App, area: Rect) {
+    let matches = app.get_jump_matches();
+
+    let list_height = (matches.len() as u16).clamp(1, MAX_VISIBLE_MATCHES);
+    let [popup_area] = Layout::vertical([Constraint::Length(list_height + 5)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup_area] = Layout::horizontal([Constraint::Percentage(70)])
+        .flex(Flex::Center)
+        .areas(popup_area);
+
+    let block = Block::default()
+        .title("Jump to movie")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let inner = block.inner(popup_area);
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(block, popup_area);
+
+    let [input_area, _, list_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(1),
+    ])
+    .areas(inner);
+
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::Yellow)),
+            Span::raw(app.jump_input.clone()),
+        ])),
+        input_area,
+    );
+
+    if matches.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                "No upcoming movies match",
+                Style::default().fg(Color::Gray),
+            )),
+            list_area,
+        );
+        return;
+    }
+
+    // Dates get a fixed column and titles share what's left, less the highlight symbol
+    let date_format = app.config.date_format.short();
+    let title_width = (list_area.width as usize).saturating_sub(24);
+    let items: Vec<ListItem> = matches
+        .iter()
+        .map(|(name, next)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", truncate_to_width(name, title_width), width = title_width),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    format!(
+                        " {} {}",
+                        next.format(date_format),
+                        format_showtime(next, app.config.use_24h)
+                    ),
+                    Style::default().fg(Color::Cyan),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .highlight_style(
+            theme(&app.config).highlight(),
+        )
+        .highlight_symbol("▶ ");
+
+    let mut state = ListState::default().with_selected(Some(app.jump_index));
+    frame.render_stateful_widget(list, list_area, &mut state);
+}
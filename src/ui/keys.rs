@@ -0,0 +1,253 @@
+//! Every key binding, grouped the way the footer shows them. The footer and the help overlay
+//! both read these tables, so a binding added here appears in both.
+
+/// Keys written the way they are shown, and what they do
+pub struct Binding {
+    pub keys: &'static str,
+    pub action: &'static str,
+}
+
+const fn bind(keys: &'static str, action: &'static str) -> Binding {
+    Binding { keys, action }
+}
+
+pub const QUIT_CONFIRM: &[Binding] = &[
+    bind("y/q", "quit"),
+    bind("n/Esc", "stay"),
+    bind("Ctrl+c", "quit without asking"),
+];
+
+pub const SEARCH: &[Binding] = &[bind("Enter", "keep filter"), bind("Esc", "discard edit")];
+
+pub const DETAIL_FIND: &[Binding] = &[bind("Enter", "done"), bind("Esc", "clear")];
+
+pub const LOADING: &[Binding] = &[bind("Esc", "cancel"), bind("q", "quit")];
+
+pub const JUMP_PALETTE: &[Binding] = &[
+    bind("↑↓/Tab", "select"),
+    bind("Enter", "jump to its next date"),
+    bind("Esc", "cancel"),
+];
+
+pub const GENRE_POPUP: &[Binding] = &[
+    bind("↑↓/jk", "select genre"),
+    bind("Enter", "apply"),
+    bind("Esc", "cancel"),
+];
+
+pub const SESSION_POPUP: &[Binding] = &[
+    bind("Enter", "book"),
+    bind("r", "remind me"),
+    bind("Esc/b", "close"),
+    bind("q", "quit"),
+];
+
+pub const MAIN_EMPTY: &[Binding] = &[
+    bind("g", "load movies"),
+    bind("m", "search movies"),
+    bind("C", "clear cache"),
+    bind("?", "help"),
+    bind("q", "quit"),
+];
+
+pub const MAIN: &[Binding] = &[
+    bind("↑↓/jk", "scroll"),
+    bind("←→/hl", "change date"),
+    bind("1-9", "jump to date"),
+    bind("t", "today"),
+    bind("Enter", "view details"),
+    bind("m", "search"),
+    bind("x", "expand"),
+    bind("Tab", "pick a time"),
+    bind(":/Ctrl+p", "jump to movie"),
+    bind("c", "copy session"),
+    bind("y", "copy day's times"),
+    bind("B", "booking page"),
+    bind("X", "export CSV"),
+    bind("J", "export JSON"),
+    bind("e", "export movie's day to calendar"),
+    bind("W", "export week to calendar"),
+    bind("n", "note"),
+    bind("f", "favorite"),
+    bind("F", "favorites only"),
+    bind("+/-", "minimum rating"),
+    bind("0", "clear minimum rating"),
+    bind(".", "last viewed"),
+    bind("g", "refresh"),
+    bind("R", "refresh this date"),
+    bind("s", "sort: name/earliest/rating"),
+    bind("G", "genre"),
+    bind("O", "12/24-hour times"),
+    bind("P", "group franchise"),
+    bind("z", "fold dates"),
+    bind("w", "week view"),
+    bind("o", "past dates"),
+    bind("u", "upcoming only"),
+    bind("D", "changes"),
+    bind("H", "heatmap"),
+    bind("T", "table"),
+    bind("U", "starting soon"),
+    bind("S", "stats"),
+    bind("M", "double features"),
+    bind("A", "reminders"),
+    bind("C", "clear cache"),
+    bind("?", "help"),
+    bind("q", "quit"),
+];
+
+pub const CLEAR_CACHE_CONFIRM: &[Binding] = &[bind("y", "delete"), bind("n/Esc", "keep")];
+
+pub const MAIN_LIST_SESSIONS: &[Binding] = &[
+    bind("←→/hl", "select time"),
+    bind("↑↓/jk", "change movie"),
+    bind("Enter", "session details"),
+    bind("Tab/Esc", "done"),
+    bind("q", "quit"),
+];
+
+pub const REGION_SESSIONS: &[Binding] = &[
+    bind("←→/hl", "select time"),
+    bind("↑↓/jk", "change movie"),
+    bind("Enter", "session details"),
+    bind("Tab/Shift+Tab", "switch region"),
+    bind("q", "quit"),
+];
+
+pub const REGION_DATES: &[Binding] = &[
+    bind("←→/hl", "change date"),
+    bind("1-9", "jump to date"),
+    bind("t", "today"),
+    bind("Tab/Shift+Tab", "switch region"),
+    bind("Enter", "view details"),
+    bind(":/Ctrl+p", "jump to movie"),
+    bind("g", "refresh"),
+    bind("R", "refresh this date"),
+    bind("A", "reminders"),
+    bind("?", "help"),
+    bind("q", "quit"),
+];
+
+pub const REGION_LIST: &[Binding] = &[
+    bind("↑↓/jk", "scroll"),
+    bind("Tab/Shift+Tab", "switch region"),
+    bind("Enter", "view details"),
+    bind("c", "copy session"),
+    bind("y", "copy day's times"),
+    bind("B", "booking page"),
+    bind("n", "note"),
+    bind(":/Ctrl+p", "jump to movie"),
+    bind("g", "refresh"),
+    bind("R", "refresh this date"),
+    bind("A", "reminders"),
+    bind("?", "help"),
+    bind("q", "quit"),
+];
+
+pub const DETAIL: &[Binding] = &[
+    bind("←→/hl", "select session"),
+    bind("Enter", "book"),
+    bind("e", "export"),
+    bind("a", "remind me"),
+    bind("d", "directions"),
+    bind("i", "copy IMDb link"),
+    bind("o", "open IMDb"),
+    bind("c", "correct match"),
+    bind("n", "note"),
+    bind("r", "refresh details"),
+    bind("p", "short/full plot"),
+    bind("1-5", "similar"),
+    bind("f", "focus sessions"),
+    bind("↑↓/jk/PgUp/PgDn", "scroll"),
+    bind("/", "find"),
+    bind("O", "12/24-hour times"),
+    bind("?", "help"),
+    bind("Esc/b", "back"),
+    bind("q", "quit"),
+];
+
+pub const DETAIL_SESSIONS: &[Binding] = &[
+    bind("↑↓/jk", "select session"),
+    bind("Enter", "book"),
+    bind("e", "export to calendar"),
+    bind("f", "unfocus"),
+    bind("Esc/b", "back"),
+    bind("q", "quit"),
+];
+
+pub const BACK_ONLY: &[Binding] = &[bind("?", "help"), bind("Esc/b", "back"), bind("q", "quit")];
+
+pub const DOUBLE_FEATURE: &[Binding] = &[
+    bind("←→/hl", "change date"),
+    bind("?", "help"),
+    bind("Esc/b", "back"),
+    bind("q", "quit"),
+];
+
+pub const UPCOMING: &[Binding] = &[
+    bind("+/-", "change window"),
+    bind("?", "help"),
+    bind("Esc/b", "back"),
+    bind("q", "quit"),
+];
+
+pub const REMINDERS: &[Binding] = &[
+    bind("↑↓/jk", "select"),
+    bind("d", "remove"),
+    bind("?", "help"),
+    bind("Esc/b", "back"),
+    bind("q", "quit"),
+];
+
+pub const OVERVIEW: &[Binding] = &[
+    bind("↑↓/jk", "scroll"),
+    bind("s", "sort column"),
+    bind("r", "reverse"),
+    bind("?", "help"),
+    bind("Esc/b", "back"),
+    bind("q", "quit"),
+];
+
+pub const HELP: &[Binding] = &[bind("↑↓/jk/PgUp/PgDn", "scroll"), bind("any other key", "close")];
+
+/// The help overlay's sections in order, each a heading over its bindings
+pub fn help_sections(focus_regions: bool) -> Vec<(&'static str, &'static [Binding])> {
+    let mut sections = if focus_regions {
+        vec![
+            ("Movie list - dates region", REGION_DATES),
+            ("Movie list - list region", REGION_LIST),
+            ("Movie list - picking a time", REGION_SESSIONS),
+        ]
+    } else {
+        vec![
+            ("Movie list", MAIN),
+            ("Movie list - picking a time", MAIN_LIST_SESSIONS),
+        ]
+    };
+    sections.extend([
+        ("Loading movies", LOADING),
+        ("Session popup", SESSION_POPUP),
+        ("Search", SEARCH),
+        ("Jump to movie", JUMP_PALETTE),
+        ("Genre filter", GENRE_POPUP),
+        ("Movie details", DETAIL),
+        ("Movie details - focused sessions", DETAIL_SESSIONS),
+        ("Find in details", DETAIL_FIND),
+        ("Starting soon", UPCOMING),
+        ("Double features", DOUBLE_FEATURE),
+        ("Table", OVERVIEW),
+        ("Reminders", REMINDERS),
+        ("Changes, heatmap and stats", BACK_ONLY),
+        ("Clear cache confirmation", CLEAR_CACHE_CONFIRM),
+        ("Quit confirmation", QUIT_CONFIRM),
+    ]);
+    sections
+}
+
+/// Joins bindings into a footer hint like "(g) refresh, (q) quit"
+pub fn hint(bindings: &[Binding]) -> String {
+    bindings
+        .iter()
+        .map(|binding| format!("({}) {}", binding.keys, binding.action))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
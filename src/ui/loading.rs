@@ -1,38 +1,72 @@
 use crate::app::App;
-use chrono::Utc;
+use crate::app::cinema::FAST_SCRAPE_WARNING;
+use super::text::truncate_to_width;
 use ratatui::{
-    layout::Rect,
-    style::Style,
-    text::Line,
-    widgets::{Block, Borders, Paragraph, Wrap},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, Paragraph, Wrap},
     Frame,
 };
 
-/// Renders the loading screen with spinner and progress messages
+/// The spinner character for an animation frame, shared by every spinner
+pub fn spinner(animation_frame: u64) -> char {
+    const SPINNER_CHARS: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+    SPINNER_CHARS[(animation_frame % SPINNER_CHARS.len() as u64) as usize]
+}
+
+/// Renders the loading screen with a progress gauge, or a spinner until the number of days
+/// is known, above the latest progress messages
 pub fn render_loading(frame: &mut Frame, app: &App, area: Rect) {
     let loading_block = Block::default()
         .title("Loading Movies")
         .borders(Borders::ALL)
         .style(Style::default());
+    let inner = loading_block.inner(area);
+    frame.render_widget(loading_block, area);
+
+    let [status_area, text_area] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(inner);
 
-    // Create spinner animation (simple rotating character)
-    let spinner_chars = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
-    let spinner_idx = (Utc::now().timestamp_millis() / 100) as usize % spinner_chars.len();
-    let spinner = spinner_chars[spinner_idx];
+    match app.loading_progress {
+        Some((done, total)) => {
+            // Nothing left to fetch counts as finished rather than dividing by zero
+            let ratio = if total == 0 { 1.0 } else { done as f64 / total as f64 };
+            let gauge = Gauge::default()
+                .gauge_style(Style::default().fg(Color::Cyan).bg(Color::DarkGray))
+                .ratio(ratio.min(1.0))
+                .label(format!("{}/{} days", done, total));
+            frame.render_widget(gauge, status_area);
+        }
+        None => {
+            let spinner = spinner(app.animation_frame());
+            frame.render_widget(
+                Paragraph::new(format!("{} Loading movie data...", spinner)),
+                status_area,
+            );
+        }
+    }
+
+    let mut loading_text = vec![Line::from("")];
 
-    let mut loading_text = vec![
-        Line::from(format!("{} Loading movie data...", spinner)),
-        Line::from(""),
-    ];
+    // Kept on screen for the whole load rather than scrolling away with the progress messages
+    if app.fast_scrape {
+        loading_text.insert(
+            0,
+            Line::from(Span::styled(
+                FAST_SCRAPE_WARNING,
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )),
+        );
+    }
 
-    // Add recent loading messages (last 5)
+    // Add recent loading messages (last 5), one line each; the full text stays in `loading_messages`
+    let message_width = text_area.width as usize;
     for message in app.loading_messages.iter().rev().take(5).rev() {
-        loading_text.push(Line::from(message.clone()));
+        loading_text.push(Line::from(truncate_to_width(message, message_width)));
     }
 
-    let loading_paragraph = Paragraph::new(loading_text)
-        .block(loading_block)
-        .wrap(Wrap { trim: true });
+    let loading_paragraph = Paragraph::new(loading_text).wrap(Wrap { trim: true });
 
-    frame.render_widget(loading_paragraph, area);
+    frame.render_widget(loading_paragraph, text_area);
 }
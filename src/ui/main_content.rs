@@ -1,15 +1,36 @@
-use crate::app::App;
-use chrono::Datelike;
+use crate::app::{App, ClickTargets, Focus, ListSort};
+use crate::app::omd::known_value;
+use crate::app::config::{DateOrder, ListSeparator, TimesOverflow, TitleOverflow, format_showtime};
+use chrono::{DateTime, Local, NaiveDate};
+use super::text::{truncate_to_width, wrap_to_width};
+use super::theme::{Palette, date_styles, theme};
+use std::ops::Range;
+use unicode_width::UnicodeWidthStr;
 use ratatui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
 };
 
+/// Shown before titles matching one of the configured interests
+const INTEREST_MARKER: &str = "◆";
+const FAVORITE_MARKER: &str = "★";
+
 /// Renders the main content area showing the movie list or empty state
 pub fn render_main_content(frame: &mut Frame, app: &mut App, area: Rect) {
+    // Loaded, but nothing came out with a usable date; the scraper probably under-matched
+    if app.available_dates.is_empty() && app.last_updated.is_some() {
+        let paragraph = Paragraph::new(Text::styled(
+            "No showtimes could be parsed — the cinema site layout may have changed. Press (g) to retry.",
+            Style::default().fg(theme(&app.config).muted),
+        ))
+        .wrap(Wrap { trim: true })
+        .block(Block::default().title("No showtimes").borders(Borders::ALL));
+        frame.render_widget(paragraph, area);
+        return;
+    }
     if app.ritz_movie_times.is_empty() {
         let empty_block = Block::default()
             .title("No movies loaded - press 'g' to load")
@@ -20,141 +41,527 @@ pub fn render_main_content(frame: &mut Frame, app: &mut App, area: Rect) {
         return;
     }
 
-    // Split the area into date header and movie list
+    app.clamp_date_selection();
+
+    // Split the area into date header and movie list; a collapsed header lives in the app header
+    let date_header_height = if app.compact_dates { 0 } else { 3 };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Date header
-            Constraint::Min(1),    // Movie list
+            Constraint::Length(date_header_height), // Date header
+            Constraint::Min(1),                     // Movie list
         ])
         .split(area);
 
-    // Render date header
-    render_date_header(frame, app, chunks[0]);
+    // Render date header, noting where its dates landed for mouse clicks
+    app.click_targets = if app.compact_dates {
+        ClickTargets::default()
+    } else {
+        render_date_header(frame, app, chunks[0])
+    };
 
     // Get filtered movies for selected date
+    app.track_date_changes();
+    // Upcoming-only hides sessions as they start, so the list can shrink between draws
+    app.clamp_list_selection();
     let movies = app.get_filtered_movies();
+    let changes = app.get_date_changes();
+    let theme = theme(&app.config);
+    let palette = theme.palette;
 
-    let title = format!(
+    let mut title = format!(
         "Movies ({} showing - Use ↑↓/jk to scroll, ←→/hl to change date)",
         movies.len()
     );
+    if !app.search_term.is_empty() {
+        title.push_str(&format!(" [search: \"{}\" - Esc to clear]", app.search_term));
+    }
+    if app.favorites_only {
+        title.push_str(" [favorites only - V to show all]");
+    }
+    if let Some(min_rating) = app.min_rating {
+        title.push_str(&format!(" [IMDb {:.1}+ - +/- to adjust, 0 to clear]", min_rating));
+    }
+    if let Some(genre) = &app.genre_filter {
+        title.push_str(&format!(" [genre: {} - G to change]", genre));
+    }
+    match app.list_sort {
+        ListSort::Alphabetical => {}
+        ListSort::EarliestShowtime => title.push_str(" [by earliest showtime]"),
+        ListSort::Rating => {
+            let (rated, listed) = app.rated_count();
+            if rated < listed {
+                title.push_str(&format!(" [by rating: {}/{} rated]", rated, listed));
+            } else {
+                title.push_str(" [by rating]");
+            }
+        }
+    }
+    if let Some(prefix) = &app.franchise_group {
+        title.push_str(&format!(" [grouped: {}]", prefix));
+    }
+    let interesting: Vec<bool> = movies.iter().map(|(name, _)| app.is_interesting(name)).collect();
+    if interesting.contains(&true) {
+        title.push_str(&format!(" [{} matches an interest]", INTEREST_MARKER));
+    }
+    if let Some(changes) = changes {
+        let removed: usize = changes.removed.values().map(Vec::len).sum();
+        title.push_str(&format!(
+            " [changed since last viewed: +{} -{}]",
+            changes.added.len(),
+            removed
+        ));
+    }
+
+    // Borders take two columns and the highlight symbol another two
+    let title_width = chunks[1].width.saturating_sub(4) as usize;
+    let title_style = Style::default()
+        .fg(theme.text)
+        .add_modifier(Modifier::BOLD);
+
+    let favorite_style = Style::default()
+        .fg(Color::LightYellow)
+        .add_modifier(Modifier::BOLD);
+    let time_style = Style::default().fg(theme.muted);
+    let focused_time_style = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::REVERSED);
+    let added_time_style = Style::default()
+        .fg(palette.good)
+        .add_modifier(Modifier::BOLD);
+    let removed_time_style = Style::default()
+        .fg(palette.poor)
+        .add_modifier(Modifier::CROSSED_OUT);
+
+    // Today's times count down to their start; redrawing every poll keeps them current
+    let now = Local::now();
+    let counting_down = app
+        .get_selected_date()
+        .is_some_and(|date| date.date_naive() == app.config.today());
+
+    // Each separator trails its item, so selection indices still map one-to-one onto movies
+    let separator = match app.config.list_separator {
+        ListSeparator::None => None,
+        ListSeparator::Blank => Some(Line::from("")),
+        ListSeparator::Rule => Some(Line::from(Span::styled(
+            "─".repeat(title_width),
+            Style::default().fg(Color::DarkGray),
+        ))),
+    };
+    let last_index = movies.len().saturating_sub(1);
 
     let items: Vec<ListItem> = movies
         .iter()
-        .map(|(name, times)| {
-            // Format times nicely
-            let mut time_strings: Vec<String> = times
-                .iter()
-                .map(|t| t.format("%I:%M %p").to_string())
-                .collect();
-            time_strings.sort();
-
-            let times_display = if time_strings.is_empty() {
-                "No times available".to_string()
+        .zip(interesting)
+        .enumerate()
+        .map(|(i, ((name, times), interesting))| {
+            // Chronological, matching the order times are stepped through when focused
+            let mut times = times.clone();
+            times.sort();
+            let focused_time = (app.list_session_focus && i == app.selected_movie_index)
+                .then_some(app.list_session_index);
+
+            let mut time_spans = Vec::new();
+            if times.is_empty() {
+                time_spans.push(Span::styled("No times available", time_style));
+            }
+            for (j, time) in times.iter().enumerate() {
+                let added = changes
+                    .is_some_and(|changes| changes.added.contains(&(name.clone(), *time)));
+                let countdown = counting_down.then(|| countdown(*time, now, &palette));
+                let style = if focused_time == Some(j) {
+                    focused_time_style
+                } else if added {
+                    added_time_style
+                } else if let Some((_, style)) = countdown {
+                    style
+                } else {
+                    time_style
+                };
+                let marker = if added && palette.symbols { "+" } else { "" };
+                let suffix = countdown.map_or(String::new(), |(text, _)| format!(" ({})", text));
+                // Standard sessions have no format, so they read exactly as before
+                let tag = app
+                    .session_details
+                    .get(name)
+                    .and_then(|sessions| sessions.get(time))
+                    .and_then(|info| info.format.as_deref())
+                    .map_or(String::new(), |format| format!(" [{}]", format));
+                time_spans.push(Span::styled(
+                    format!(
+                        "{}{}{}{}",
+                        marker,
+                        format_showtime(time, app.config.use_24h),
+                        tag,
+                        suffix
+                    ),
+                    style,
+                ));
+            }
+            // Times dropped since last viewed linger briefly after the current ones
+            if let Some(removed) = changes.and_then(|changes| changes.removed.get(name)) {
+                for time in removed {
+                    time_spans.push(Span::styled(
+                        format!("-{}", format_showtime(time, app.config.use_24h)),
+                        removed_time_style,
+                    ));
+                }
+            }
+
+            // Create the movie lines with name and times
+            let favorite = app.favorites.contains(name);
+            let mut name_width = title_width;
+            if interesting {
+                name_width = name_width.saturating_sub(INTEREST_MARKER.width() + 1);
+            }
+            if favorite {
+                name_width = name_width.saturating_sub(FAVORITE_MARKER.width() + 1);
+            }
+            let name_style = if favorite { favorite_style } else { title_style };
+            let mut content: Vec<Line> = match app.config.title_overflow {
+                TitleOverflow::Truncate => vec![Line::from(Span::styled(
+                    truncate_to_width(name, name_width),
+                    name_style,
+                ))],
+                TitleOverflow::Wrap => wrap_to_width(name, name_width)
+                    .into_iter()
+                    .map(|line| Line::from(Span::styled(line, name_style)))
+                    .collect(),
+            };
+            if favorite && let Some(first) = content.first_mut() {
+                first
+                    .spans
+                    .insert(0, Span::styled(format!("{} ", FAVORITE_MARKER), favorite_style));
+            }
+            if interesting && let Some(first) = content.first_mut() {
+                first.spans.insert(
+                    0,
+                    Span::styled(format!("{} ", INTEREST_MARKER), Style::default().fg(Color::Magenta)),
+                );
+            }
+            // While picking a time every time is shown, so the focused one is never hidden
+            let overflow = if focused_time.is_some() {
+                TimesOverflow::Wrap
             } else {
-                time_strings.join(", ")
+                app.config.times_overflow
             };
-
-            // Create the movie line with name and times
-            let content = vec![
-                Line::from(vec![Span::styled(
-                    name.to_string(),
-                    Style::default()
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
-                )]),
-                Line::from(vec![Span::styled(
-                    format!("  {}", times_display),
-                    Style::default().fg(Color::Gray),
-                )]),
-            ];
+            content.extend(time_lines(time_spans, title_width, overflow, time_style));
+            if let Some(note) = app.get_note(name) {
+                content.push(Line::from(Span::styled(
+                    truncate_to_width(&format!("  ✎ {}", note), title_width),
+                    Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+                )));
+            }
+            if app.expanded_movie.as_deref() == Some(name.as_str()) {
+                content.extend(expanded_lines(app, name, title_width));
+            }
+            if let Some(separator) = &separator
+                && i < last_index
+            {
+                content.push(separator.clone());
+            }
 
             ListItem::new(content)
         })
         .collect();
 
+    let list_block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(region_style(app, Focus::List));
+
+    let filtered = !app.search_term.is_empty()
+        || app.genre_filter.is_some()
+        || app.favorites_only
+        || app.min_rating.is_some();
+    if movies.is_empty() && filtered {
+        let message = match &app.genre_filter {
+            _ if !app.search_term.is_empty() => {
+                format!("No movies match \"{}\" on this date", app.search_term)
+            }
+            Some(genre) => format!("No {} movies with fetched details on this date", genre),
+            None if app.favorites_only => {
+                "No favorites on this date - (F) shows every movie".to_string()
+            }
+            None => "No movies rated this highly on this date - (0) clears the minimum".to_string(),
+        };
+        let message = Paragraph::new(Text::styled(
+            message,
+            Style::default().fg(theme.muted),
+        ))
+        .block(list_block);
+        frame.render_widget(message, chunks[1]);
+        return;
+    }
+
+    let list_area = list_block.inner(chunks[1]);
+    app.click_targets.list = Some(list_area);
+    app.click_targets.item_heights = items.iter().map(|item| item.height() as u16).collect();
+
     let list = List::new(items)
-        .block(Block::default().title(title).borders(Borders::ALL))
+        .block(list_block)
         .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            theme.highlight(),
         )
         .highlight_symbol("▶ ");
 
     frame.render_stateful_widget(list, chunks[1], &mut app.list_state);
 }
 
+/// The extra lines under an expanded movie: cached OMDb basics and every upcoming day's times
+fn expanded_lines(app: &App, name: &str, width: usize) -> Vec<Line<'static>> {
+    let theme = theme(&app.config);
+    let label_style = Style::default().fg(theme.muted);
+    let value_style = Style::default().fg(theme.text);
+    let mut lines = Vec::new();
+
+    if let Some(movie) = app.detail_cache.get(name) {
+        let facts: Vec<String> = [
+            known_value(&movie.genre).map(str::to_string),
+            known_value(&movie.runtime).map(str::to_string),
+            known_value(&movie.imdb_rating).map(|rating| format!("IMDb {}/10", rating)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if !facts.is_empty() {
+            lines.push(Line::from(Span::styled(
+                truncate_to_width(&format!("    {}", facts.join(" · ")), width),
+                value_style,
+            )));
+        }
+    }
+
+    let sessions = app.get_upcoming_sessions(name);
+    let times: Vec<_> = sessions.iter().map(|(time, _)| *time).collect();
+    for day in times.chunk_by(|a, b| a.date_naive() == b.date_naive()) {
+        let label = format!("    {:<10} ", day[0].format(app.config.date_format.short()));
+        let day_times = day
+            .iter()
+            .map(|time| format_showtime(time, app.config.use_24h))
+            .collect::<Vec<_>>()
+            .join(", ");
+        lines.push(Line::from(vec![
+            Span::styled(label.clone(), label_style),
+            Span::styled(
+                truncate_to_width(&day_times, width.saturating_sub(label.width())),
+                value_style,
+            ),
+        ]));
+    }
+    if times.is_empty() {
+        lines.push(Line::from(Span::styled("    No upcoming sessions", label_style)));
+    }
+    lines
+}
+
+/// Sessions starting within this many minutes are flagged as soon
+const SOON_MINUTES: i64 = 30;
+
+/// How long until a session of today's starts, e.g. "in 45 min" or "started", styled by how
+/// soon that is
+fn countdown(time: DateTime<Local>, now: DateTime<Local>, palette: &Palette) -> (String, Style) {
+    let minutes = (time - now).num_minutes();
+    if time <= now {
+        let style = Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::CROSSED_OUT);
+        return ("started".to_string(), style);
+    }
+
+    let text = match minutes {
+        0 => "starting".to_string(),
+        1..60 => format!("in {} min", minutes),
+        _ => format!("in {}h {:02}m", minutes / 60, minutes % 60),
+    };
+    let color = if minutes <= SOON_MINUTES { palette.fair } else { palette.good };
+    (text, Style::default().fg(color))
+}
+
+/// Lays out indented, comma-separated times within `width` columns, wrapping onto more
+/// lines or ending with a "+N" count of those left out
+fn time_lines(
+    times: Vec<Span<'static>>,
+    width: usize,
+    overflow: TimesOverflow,
+    separator_style: Style,
+) -> Vec<Line<'static>> {
+    const INDENT: &str = "  ";
+    const SEPARATOR: &str = ", ";
+
+    let total = times.len();
+    let mut lines = Vec::new();
+    let mut spans = vec![Span::styled(INDENT, separator_style)];
+    let mut used = INDENT.width();
+    let mut on_line = 0;
+
+    for (i, time) in times.into_iter().enumerate() {
+        let time_width = time.content.width();
+        let separator_width = if on_line > 0 { SEPARATOR.width() } else { 0 };
+
+        if on_line > 0 && used + separator_width + time_width > width {
+            match overflow {
+                TimesOverflow::Wrap => {
+                    lines.push(Line::from(std::mem::take(&mut spans)));
+                    spans.push(Span::styled(INDENT, separator_style));
+                    used = INDENT.width();
+                    on_line = 0;
+                }
+                TimesOverflow::Truncate => {
+                    append_remaining(&mut spans, used, width, total - i, separator_style);
+                    lines.push(Line::from(spans));
+                    return lines;
+                }
+            }
+        }
+
+        if on_line > 0 {
+            spans.push(Span::styled(SEPARATOR, separator_style));
+            used += SEPARATOR.width();
+        }
+        spans.push(time);
+        used += time_width;
+        on_line += 1;
+    }
+
+    lines.push(Line::from(spans));
+    lines
+}
+
+/// Ends a truncated times line with "+N", dropping trailing times until the count fits
+fn append_remaining(
+    spans: &mut Vec<Span<'static>>,
+    mut used: usize,
+    width: usize,
+    mut remaining: usize,
+    style: Style,
+) {
+    // Spans alternate indent, time, separator, time...; keep at least the first time
+    while spans.len() > 2 && used + format!(" +{}", remaining).width() > width {
+        let time = spans.pop().map_or(0, |span| span.content.width());
+        let separator = spans.pop().map_or(0, |span| span.content.width());
+        used -= time + separator;
+        remaining += 1;
+    }
+    spans.push(Span::styled(format!(" +{}", remaining), style));
+}
+
+/// Border style marking the region that takes arrow keys, when regions are focusable
+fn region_style(app: &App, region: Focus) -> Style {
+    if app.is_focused(region) {
+        Style::default().fg(theme(&app.config).accent)
+    } else {
+        Style::default()
+    }
+}
+
 /// Renders the date header showing current selected date
-fn render_date_header(frame: &mut Frame, app: &App, area: Rect) {
-    if app.available_dates.is_empty() {
+fn render_date_header(frame: &mut Frame, app: &App, area: Rect) -> ClickTargets {
+    let dates = app.visible_dates();
+    if dates.is_empty() {
+        // Everything cached may be in the past, which is hidden by default
+        let message = if app.available_dates.is_empty() {
+            "No dates available"
+        } else {
+            "No upcoming dates - press (o) to show past dates or (g) to refresh"
+        };
         let paragraph = Paragraph::new(Text::styled(
-            "No dates available",
-            Style::default().fg(Color::Gray),
+            message,
+            Style::default().fg(theme(&app.config).muted),
         ))
-        .block(Block::default().borders(Borders::ALL).title("Dates"));
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Dates")
+                .border_style(region_style(app, Focus::Dates)),
+        );
         frame.render_widget(paragraph, area);
-        return;
+        return ClickTargets::default();
     }
 
-    let today = chrono::Local::now();
+    let today = app.config.today();
 
     // Calculate approximate space needed for horizontal display
     // Each date takes roughly: "Mon 02/04" = ~10 chars + 3 spacing = 13 chars per date
     let estimated_width_per_date = 13;
     let available_width = area.width.saturating_sub(4); // Account for borders and padding
-    let total_estimated_width = app.available_dates.len() * estimated_width_per_date;
+    let total_estimated_width = dates.len() * estimated_width_per_date;
+
+    let fits = (available_width as usize / estimated_width_per_date).max(1);
 
     // If we have space, show all dates horizontally
     if total_estimated_width <= available_width as usize {
-        render_horizontal_dates(frame, app, area, &today);
+        ClickTargets {
+            dates: render_horizontal_dates(frame, app, area, &today, 0..dates.len()),
+            ..ClickTargets::default()
+        }
+    } else if app.config.date_order == DateOrder::TodayCentered {
+        // Scroll a window of dates so the selection stays in the middle
+        let start = app
+            .selected_date_index
+            .saturating_sub(fits / 2)
+            .min(dates.len().saturating_sub(fits));
+        let range = start..(start + fits).min(dates.len());
+        ClickTargets {
+            dates: render_horizontal_dates(frame, app, area, &today, range),
+            ..ClickTargets::default()
+        }
     } else {
         // Fall back to single date display with position indicator
         render_single_date(frame, app, area, &today);
+        ClickTargets {
+            single_date: Some(area),
+            ..ClickTargets::default()
+        }
     }
 }
 
-/// Renders all dates horizontally with the selected one highlighted
+/// Renders the dates in `range` horizontally with the selected one highlighted, returning
+/// where each date was drawn
 fn render_horizontal_dates(
     frame: &mut Frame,
     app: &App,
     area: Rect,
-    today: &chrono::DateTime<chrono::Local>,
-) {
+    today: &NaiveDate,
+    range: Range<usize>,
+) -> Vec<(Rect, usize)> {
+    let styles = date_styles(&app.config);
+    let text = theme(&app.config).text;
     let mut spans = Vec::new();
+    let mut cells = Vec::new();
+    let first = range.start;
+    // Inside the left border, on the row between the top and bottom borders
+    let mut x = area.x + 1;
 
-    for (i, date) in app.available_dates.iter().enumerate() {
-        if i > 0 {
+    for (i, date) in app.visible_dates().iter().enumerate().take(range.end).skip(first) {
+        if i > first {
             spans.push(Span::raw("  "));
+            x += 2;
         }
 
         let is_selected = i == app.selected_date_index;
-        let is_today = date.year() == today.year()
-            && date.month() == today.month()
-            && date.day() == today.day();
+        let is_today = date.date_naive() == *today;
 
-        // Format: "Mon 02/04" or "Today" for current day
+        // Format: "Mon 02/04" (per the date format preset) or "Today" for current day
         let date_str = if is_today {
             "Today".to_string()
         } else {
-            date.format("%a %m/%d").to_string()
+            date.format(app.config.date_format.short()).to_string()
         };
 
-        let style = if is_selected {
-            Style::default()
-                .fg(Color::Yellow)
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD)
+        let style = if is_selected && is_today {
+            styles.selected_today
+        } else if is_selected {
+            styles.selected
         } else if is_today {
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD)
+            styles.today
         } else {
-            Style::default().fg(Color::White)
+            Style::default().fg(text)
         };
 
+        let width = date_str.width() as u16;
+        cells.push((Rect::new(x, area.y + 1, width, 1).intersection(area), i));
+        x += width;
         spans.push(Span::styled(date_str, style));
     }
 
@@ -162,10 +569,12 @@ fn render_horizontal_dates(
     let paragraph = Paragraph::new(line).block(
         Block::default()
             .borders(Borders::ALL)
-            .title("Dates (←→ or h/l to navigate)"),
+            .title("Dates (←→ or h/l to navigate)")
+            .border_style(region_style(app, Focus::Dates)),
     );
 
     frame.render_widget(paragraph, area);
+    cells
 }
 
 /// Renders a single date with position indicator (fallback for narrow screens)
@@ -173,15 +582,13 @@ fn render_single_date(
     frame: &mut Frame,
     app: &App,
     area: Rect,
-    today: &chrono::DateTime<chrono::Local>,
+    today: &NaiveDate,
 ) {
     let date_text = if let Some(date) = app.get_selected_date() {
-        let is_today = date.year() == today.year()
-            && date.month() == today.month()
-            && date.day() == today.day();
+        let is_today = date.date_naive() == *today;
 
         let day_name = date.format("%A").to_string();
-        let date_str = date.format("%B %d, %Y").to_string();
+        let date_str = date.format(app.config.date_format.long()).to_string();
 
         let prefix = if is_today { "Today - " } else { "" };
 
@@ -190,11 +597,12 @@ fn render_single_date(
         "No dates available".to_string()
     };
 
-    let date_indicator = if app.available_dates.len() > 1 {
+    let date_count = app.visible_dates().len();
+    let date_indicator = if date_count > 1 {
         format!(
             " [{}/{}]",
             app.selected_date_index + 1,
-            app.available_dates.len()
+            date_count
         )
     } else {
         String::new()
@@ -211,7 +619,8 @@ fn render_single_date(
     .block(
         Block::default()
             .borders(Borders::ALL)
-            .title("Selected Date"),
+            .title("Selected Date")
+            .border_style(region_style(app, Focus::Dates)),
     )
     .style(Style::default());
 
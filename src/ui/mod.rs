@@ -1,8 +1,26 @@
+mod banner;
+mod diff;
+mod double_feature;
 mod footer;
+mod genre_popup;
 mod header;
+mod help;
+mod heatmap;
+mod jump_palette;
+mod keys;
 mod loading;
 mod main_content;
 mod movie_detail;
+mod overview;
+mod quit_confirm;
+mod reminders;
+mod session_popup;
+mod stats;
+mod text;
+mod theme;
+mod upcoming;
+mod week;
+#[allow(clippy::module_inception)]
 pub mod ui;
 
 pub use ui::ui;
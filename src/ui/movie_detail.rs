@@ -1,32 +1,56 @@
-use crate::app::App;
-use chrono::Utc;
+use crate::app::config::{CompactDetail, Config, format_showtime, get_config_path};
+use crate::app::{App, SessionInfo};
+use crate::app::omd::{OmdbError, known_value};
+use super::text::{truncate_to_width, wrap_to_width};
+use super::theme::{Level, Palette, palette, theme};
+use chrono::{DateTime, Local, Timelike};
+use super::loading::spinner;
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout, Rect, Alignment},
+    layout::{Constraint, Direction, Flex, Layout, Rect, Alignment},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
 };
 use ratatui_image::{StatefulImage, Resize, protocol::StatefulProtocol};
-use tui_big_text::{BigText, PixelSize};
+use unicode_width::UnicodeWidthStr;
+
+/// Inner widths below this use the compact layout when `compact_detail` is `auto`
+const COMPACT_DETAIL_WIDTH: u16 = 80;
+/// Share of the inner height the full layout gives the poster, so short terminals keep room
+/// for the text
+const POSTER_HEIGHT_PERCENT: u16 = 45;
+/// Tallest the poster gets in the full layout
+const MAX_POSTER_HEIGHT: u16 = 30;
+/// Shorter than this a poster is unrecognisable, so the section is dropped instead
+const MIN_POSTER_HEIGHT: u16 = 6;
+/// Poster height in the compact layout
+const COMPACT_POSTER_HEIGHT: u16 = 12;
+/// Below this inner height the compact layout drops the poster so the text stays readable
+const COMPACT_POSTER_MIN_HEIGHT: u16 = 36;
 
 /// Renders the movie detail screen
 pub fn render_movie_detail(frame: &mut Frame, app: &mut App, area: Rect) {
     // Check if loading
     if app.loading_movie_detail {
-        render_loading_state(frame, area);
+        let message = if app.refreshing_detail {
+            "Refreshing from OMDb (skipping cached details)..."
+        } else {
+            "Fetching movie details from OMDb..."
+        };
+        render_loading_state(frame, area, message, app.animation_frame());
         return;
     }
 
     // Check if API key is missing
     if app.omdb_api_key.is_none() {
-        render_missing_api_key(frame, area);
+        render_without_api_key(frame, area, app);
         return;
     }
 
     // Check for errors
     if let Some(error) = &app.movie_detail_error {
-        render_error_state(frame, area, error);
+        render_error_state(frame, area, error, app.omdb_error.as_ref(), palette(&app.config).error);
         return;
     }
 
@@ -39,22 +63,19 @@ pub fn render_movie_detail(frame: &mut Frame, app: &mut App, area: Rect) {
 }
 
 /// Renders loading state with spinner
-fn render_loading_state(frame: &mut Frame, area: Rect) {
+fn render_loading_state(frame: &mut Frame, area: Rect, message: &str, animation_frame: u64) {
     let loading_block = Block::default()
         .title("Movie Details")
         .borders(Borders::ALL)
         .style(Style::default());
 
-    // Create spinner animation
-    let spinner_chars = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
-    let spinner_idx = (Utc::now().timestamp_millis() / 100) as usize % spinner_chars.len();
-    let spinner = spinner_chars[spinner_idx];
+    let spinner = spinner(animation_frame);
 
     let loading_text = vec![
         Line::from(""),
         Line::from(""),
         Line::from(Span::styled(
-            format!("{} Fetching movie details from OMDb...", spinner),
+            format!("{} {}", spinner, message),
             Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
         )),
     ];
@@ -70,9 +91,7 @@ fn render_loading_state(frame: &mut Frame, area: Rect) {
 fn render_poster_section(frame: &mut Frame, area: Rect, app: &mut App) {
     if app.loading_poster {
         // Show loading spinner
-        let spinner_chars = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
-        let spinner_idx = (Utc::now().timestamp_millis() / 100) as usize % spinner_chars.len();
-        let spinner = spinner_chars[spinner_idx];
+        let spinner = spinner(app.animation_frame());
 
         let loading_text = vec![
             Line::from(""),
@@ -90,19 +109,33 @@ fn render_poster_section(frame: &mut Frame, area: Rect, app: &mut App) {
         frame.render_widget(loading_paragraph, area);
     } else if let Some(protocol) = &mut app.poster_protocol {
         // Render the poster
-        let image = StatefulImage::<StatefulProtocol>::default()
-            .resize(Resize::Fit(None));
+        let resize = Resize::Fit(None);
+        let image = StatefulImage::<StatefulProtocol>::default().resize(resize.clone());
 
         let poster_block = Block::default()
             .borders(Borders::ALL)
-            .title("Poster");
+            .title("Poster")
+            .title_bottom(Span::styled(
+                format!(" {} ", app.picker_source),
+                Style::default().fg(Color::DarkGray),
+            ));
 
         let inner_area = poster_block.inner(area);
         frame.render_widget(poster_block, area);
-        frame.render_stateful_widget(image, inner_area, protocol);
+
+        // Fit keeps the image's proportions but draws from the top-left corner, so center the
+        // cells it fills, whichever way the image is oriented
+        let size = protocol.size_for(resize, inner_area);
+        let [image_area] = Layout::horizontal([Constraint::Length(size.width)])
+            .flex(Flex::Center)
+            .areas(inner_area);
+        let [image_area] = Layout::vertical([Constraint::Length(size.height)])
+            .flex(Flex::Center)
+            .areas(image_area);
+        frame.render_stateful_widget(image, image_area, protocol);
     } else {
-        // Show placeholder
-        let placeholder_text = vec![
+        // Show placeholder, with the reason when a download failed
+        let mut placeholder_text = vec![
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
@@ -110,6 +143,12 @@ fn render_poster_section(frame: &mut Frame, area: Rect, app: &mut App) {
                 Style::default().fg(Color::Gray),
             )),
         ];
+        if let Some(error) = &app.poster_error {
+            placeholder_text.push(Line::from(Span::styled(
+                truncate_to_width(error, area.width.saturating_sub(2) as usize),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
 
         let placeholder_paragraph = Paragraph::new(placeholder_text)
             .alignment(Alignment::Center)
@@ -119,106 +158,173 @@ fn render_poster_section(frame: &mut Frame, area: Rect, app: &mut App) {
     }
 }
 
-/// Renders missing API key error with big text
-fn render_missing_api_key(frame: &mut Frame, area: Rect) {
+/// Renders what's known without OMDb: the scraped title and its week of sessions, with
+/// the steps to set a key for the full details
+fn render_without_api_key(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(name) = app.detail_movie.as_deref() else {
+        render_empty_state(frame, area);
+        return;
+    };
+    let error_color = palette(&app.config).error;
+
+    let outer_block = Block::default()
+        .title(format!("Movie Details - {}", name))
+        .borders(Borders::ALL);
+    let inner_area = outer_block.inner(area);
+    frame.render_widget(outer_block, area);
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(8),  // Big text
-            Constraint::Min(5),     // Instructions
+            Constraint::Length(4), // Sessions
+            Constraint::Min(3),    // Schedule
+            Constraint::Length(7), // Key instructions
         ])
-        .split(area);
-
-    // Big red text
-    let big_text = BigText::builder()
-        .pixel_size(PixelSize::Quadrant)
-        .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
-        .lines(vec!["API KEY".into(), "REQUIRED!".into()])
-        .alignment(Alignment::Center)
-        .build();
+        .split(inner_area);
 
-    frame.render_widget(big_text, chunks[0]);
+    render_sessions_section(
+        frame,
+        chunks[0],
+        &app.get_detail_sessions(),
+        app.detail_session_index,
+        &app.config,
+        app.detail_session_focus,
+    );
+
+    // Every cached session, past ones included, one day per line
+    let mut times = app.ritz_movie_times.get(name).cloned().unwrap_or_default();
+    times.sort();
+    let mut schedule = vec![Line::from(Span::styled(
+        "Schedule:",
+        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+    ))];
+    for day in times.chunk_by(|a, b| a.date_naive() == b.date_naive()) {
+        let day_times = day
+            .iter()
+            .map(|time| format_showtime(time, app.config.use_24h))
+            .collect::<Vec<_>>()
+            .join(", ");
+        schedule.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<12}", day[0].format(app.config.date_format.short())),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(day_times, Style::default().fg(Color::White)),
+        ]));
+    }
+    if times.is_empty() {
+        schedule.push(Line::from(Span::styled(
+            "  No sessions loaded",
+            Style::default().fg(Color::Gray),
+        )));
+    }
+    frame.render_widget(
+        Paragraph::new(schedule).wrap(Wrap { trim: false }),
+        chunks[1],
+    );
 
-    // Instructions
     let instructions = vec![
-        Line::from(""),
         Line::from(Span::styled(
-            "Please set your OMDb API key to view movie details",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            "Set an OMDb API key for the plot, ratings and poster",
+            Style::default().fg(error_color).add_modifier(Modifier::BOLD),
         )),
-        Line::from(""),
         Line::from(Span::styled(
             "1. Get a free key at: http://www.omdbapi.com/apikey.aspx",
             Style::default().fg(Color::White),
         )),
         Line::from(Span::styled(
-            "2. Set environment variable: export OMDB_API_KEY=your_key_here",
+            "2. Set environment variable: export OMDB_API_KEY=your_key_here (checked first)",
             Style::default().fg(Color::White),
         )),
         Line::from(Span::styled(
-            "3. Restart the application",
+            format!(
+                "   or add \"omdb_api_key\": \"your_key_here\" to {}",
+                get_config_path().display()
+            ),
             Style::default().fg(Color::White),
         )),
-        Line::from(""),
         Line::from(Span::styled(
-            "Press (Esc) or (b) to go back",
-            Style::default().fg(Color::Gray),
+            "3. Restart the application",
+            Style::default().fg(Color::White),
         )),
     ];
-
-    let instructions_block = Block::default()
-        .borders(Borders::ALL)
-        .style(Style::default());
-
-    let instructions_paragraph = Paragraph::new(instructions)
-        .block(instructions_block)
-        .alignment(Alignment::Center)
-        .wrap(Wrap { trim: true });
-
-    frame.render_widget(instructions_paragraph, chunks[1]);
+    frame.render_widget(
+        Paragraph::new(instructions)
+            .block(Block::default().borders(Borders::TOP))
+            .wrap(Wrap { trim: true }),
+        chunks[2],
+    );
 }
 
 /// Renders error state
-fn render_error_state(frame: &mut Frame, area: Rect, error: &str) {
+fn render_error_state(
+    frame: &mut Frame,
+    area: Rect,
+    error: &str,
+    refusal: Option<&OmdbError>,
+    error_color: Color,
+) {
     let error_block = Block::default()
         .title("Error")
         .borders(Borders::ALL)
         .style(Style::default());
 
-    let error_text = vec![
+    // OMDb's own refusals each have one fix, so that is offered instead of the guesses
+    let (lead, remedies): (&str, &[&str]) = match refusal {
+        Some(OmdbError::InvalidKey) => (
+            "To fix it:",
+            &[
+                "- Check omdb_api_key in the config file, or pass a key with --api-key",
+                "- New keys only work after following the activation link OMDb emails",
+            ],
+        ),
+        Some(OmdbError::RateLimited) => (
+            "To fix it:",
+            &[
+                "- Wait until tomorrow, when the key's daily allowance resets",
+                "- Movies already viewed still open from the detail cache",
+            ],
+        ),
+        Some(OmdbError::NotFound(_)) => (
+            "To fix it:",
+            &["- Press (c) to enter the movie's IMDb ID, e.g. tt1160419"],
+        ),
+        None => (
+            "This might happen if:",
+            &[
+                "- The movie title doesn't match OMDb database (press (c) to enter its IMDb ID)",
+                "- Network connection issues",
+                "- API rate limit reached",
+            ],
+        ),
+    };
+
+    let mut error_text = vec![
         Line::from(""),
         Line::from(Span::styled(
             "Failed to fetch movie details",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            Style::default().fg(error_color).add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(Span::styled(
             error,
-            Style::default().fg(Color::Red),
+            Style::default().fg(error_color),
         )),
         Line::from(""),
-        Line::from(Span::styled(
-            "This might happen if:",
-            Style::default().fg(Color::Yellow),
-        )),
-        Line::from(Span::styled(
-            "- The movie title doesn't match OMDb database",
-            Style::default().fg(Color::White),
-        )),
-        Line::from(Span::styled(
-            "- Network connection issues",
-            Style::default().fg(Color::White),
-        )),
-        Line::from(Span::styled(
-            "- API rate limit reached",
-            Style::default().fg(Color::White),
-        )),
+        Line::from(Span::styled(lead, Style::default().fg(Color::Yellow))),
+    ];
+    error_text.extend(
+        remedies
+            .iter()
+            .map(|remedy| Line::from(Span::styled(*remedy, Style::default().fg(Color::White)))),
+    );
+    error_text.extend([
         Line::from(""),
         Line::from(Span::styled(
             "Press (Esc) or (b) to go back",
             Style::default().fg(Color::Gray),
         )),
-    ];
+    ]);
 
     let error_paragraph = Paragraph::new(error_text)
         .block(error_block)
@@ -233,26 +339,66 @@ fn render_movie_info(frame: &mut Frame, area: Rect, app: &mut App) {
     // Get movie reference first to avoid borrow conflicts
     let movie = app.selected_movie_detail.as_ref().unwrap();
     
+    let screening_note = if app.ritz_movie_times.is_empty() || app.is_detail_movie_screening() {
+        ""
+    } else {
+        " (no longer screening)"
+    };
+
     let outer_block = Block::default()
-        .title(format!("Movie Details - {}", movie.title))
+        .title(format!("Movie Details - {}{}", movie.title, screening_note))
         .borders(Borders::ALL)
         .style(Style::default());
 
     let inner_area = outer_block.inner(area);
     frame.render_widget(outer_block, area);
 
+    // The compact layout shrinks the poster, or drops it when there isn't room for it and the text.
+    // With posters turned off the section is dropped entirely rather than left as a placeholder.
+    let compact = match app.config.compact_detail {
+        CompactDetail::Auto => inner_area.width < COMPACT_DETAIL_WIDTH,
+        CompactDetail::Always => true,
+        CompactDetail::Never => false,
+    };
+    let poster_height = match (app.config.detail_posters, compact) {
+        (false, _) => 0,
+        (true, false) => {
+            let share = inner_area.height as u32 * POSTER_HEIGHT_PERCENT as u32 / 100;
+            let height = share.min(MAX_POSTER_HEIGHT as u32) as u16;
+            if height >= MIN_POSTER_HEIGHT { height } else { 0 }
+        }
+        (true, true) if inner_area.height >= COMPACT_POSTER_MIN_HEIGHT => COMPACT_POSTER_HEIGHT,
+        (true, true) => 0,
+    };
+    // The timeline follows the day of the highlighted session
+    let sessions = app.get_detail_sessions();
+    let timeline: Vec<DateTime<Local>> = match (
+        sessions
+            .get(app.detail_session_index)
+            .or(sessions.first())
+            .map(|(time, _)| time.date_naive()),
+        app.detail_movie.as_ref().and_then(|name| app.ritz_movie_times.get(name)),
+    ) {
+        (Some(day), Some(times)) => times.iter().filter(|time| time.date_naive() == day).copied().collect(),
+        _ => Vec::new(),
+    };
+    let timeline_height = if timeline.is_empty() { 0 } else { 3 };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(30), // Poster section (fixed height)
+            Constraint::Length(poster_height), // Poster section
             Constraint::Length(3),  // Title info
+            Constraint::Length(4),  // Sessions
+            Constraint::Length(timeline_height), // Sessions by hour
             Constraint::Min(10),    // Content
             Constraint::Length(1),  // Footer
         ])
         .split(inner_area);
 
     // Poster section
-    render_poster_section(frame, chunks[0], app);
+    if poster_height > 0 {
+        render_poster_section(frame, chunks[0], app);
+    }
 
     // Get movie reference again for subsequent sections
     let movie = app.selected_movie_detail.as_ref().unwrap();
@@ -260,74 +406,306 @@ fn render_movie_info(frame: &mut Frame, area: Rect, app: &mut App) {
     // Title section
     render_title_section(frame, chunks[1], movie);
 
+    // Sessions with booking links
+    render_sessions_section(
+        frame,
+        chunks[2],
+        &sessions,
+        app.detail_session_index,
+        &app.config,
+        app.detail_session_focus,
+    );
+
+    if timeline_height > 0 {
+        render_timeline_section(frame, chunks[3], &timeline, &app.config);
+    }
+
     // Main content
-    render_content_section(frame, chunks[2], movie);
-
-    // Footer
-    let footer = Paragraph::new(Line::from(Span::styled(
-        "Press (Esc) or (b) to go back, (q) to quit",
-        Style::default().fg(Color::Gray),
-    )))
-    .alignment(Alignment::Center);
-    frame.render_widget(footer, chunks[3]);
+    let mut content = content_lines(movie, &app.config);
+    content.extend(similar_lines(&app.get_similar_movies()));
+    if app.config.show_cinema_address {
+        content.extend(cinema_lines(&app.config));
+    }
+    // A personal note leads the content so it's the first thing seen
+    if let Some(note) = app.detail_movie.as_deref().and_then(|name| app.get_note(name)) {
+        let note_lines = [
+            Line::from(vec![
+                Span::styled("Your note: ", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::styled(note.to_string(), Style::default().fg(Color::Yellow)),
+            ]),
+            Line::from(""),
+        ];
+        content.splice(0..0, note_lines);
+    }
+    let search = app.detail_search.to_lowercase();
+    if !search.is_empty() {
+        let matches: Vec<usize> = content
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line_text(line).to_lowercase().contains(&search))
+            .map(|(i, _)| i)
+            .collect();
+        // Follow the first match while the query is still being typed
+        if app.detail_searching
+            && let Some(&first) = matches.first()
+        {
+            app.detail_scroll = first;
+        }
+        for i in matches {
+            content[i] = content[i]
+                .clone()
+                .patch_style(Style::default().bg(Color::DarkGray));
+        }
+    }
+    // Stop once the last line is on screen, rather than scrolling into empty space
+    let width = chunks[4].width as usize;
+    let height = chunks[4].height as usize;
+    app.detail_page_height = height;
+    app.detail_scroll = app.detail_scroll.min(last_page_start(&content, width, height));
+    let visible: Vec<Line> = content.into_iter().skip(app.detail_scroll).collect();
+    frame.render_widget(Paragraph::new(visible).wrap(Wrap { trim: true }), chunks[4]);
+
+    // Footer doubles as the find box while searching
+    let footer = if app.detail_searching {
+        Paragraph::new(Line::from(vec![
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::raw(app.detail_search.clone()),
+        ]))
+    } else {
+        Paragraph::new(Line::from(Span::styled(
+            "Press (Esc) or (b) to go back, (/) to find, (c) to correct a wrong match, (q) to quit",
+            Style::default().fg(Color::Gray),
+        )))
+        .alignment(Alignment::Center)
+    };
+    frame.render_widget(footer, chunks[5]);
 }
 
 /// Renders the title section with basic info
 fn render_title_section(frame: &mut Frame, area: Rect, movie: &crate::app::omd::Welcome) {
-    let title_info = vec![
-        Line::from(vec![
-            Span::styled(&movie.title, Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
-            Span::raw(" "),
-            Span::styled(format!("({})", movie.year), Style::default().fg(Color::Cyan)),
-        ]),
-        Line::from(vec![
-            Span::styled("Rating: ", Style::default().fg(Color::Gray)),
-            Span::styled(&movie.rated, Style::default().fg(Color::White)),
-            Span::raw(" | "),
-            Span::styled("Runtime: ", Style::default().fg(Color::Gray)),
-            Span::styled(&movie.runtime, Style::default().fg(Color::White)),
-            Span::raw(" | "),
-            Span::styled("Genre: ", Style::default().fg(Color::Gray)),
-            Span::styled(&movie.genre, Style::default().fg(Color::White)),
-        ]),
+    let paragraph = Paragraph::new(title_lines(movie, area.width as usize));
+    frame.render_widget(paragraph, area);
+}
+
+/// Lays out the title and basic info within `width` columns: the genre drops to its own
+/// line when it doesn't fit beside the rating, and anything still too long gets an ellipsis
+fn title_lines(movie: &crate::app::omd::Welcome, width: usize) -> Vec<Line<'static>> {
+    let label_style = Style::default().fg(Color::Gray);
+    let value_style = Style::default().fg(Color::White);
+
+    let year = if is_known(&movie.year) {
+        format!(" ({})", movie.year)
+    } else {
+        String::new()
+    };
+    let heading = Line::from(vec![
+        Span::styled(
+            truncate_to_width(&movie.title, width.saturating_sub(year.width())),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(year, Style::default().fg(Color::Cyan)),
+    ]);
+
+    let rated = or_unknown(&movie.rated).to_string();
+    let runtime = or_unknown(&movie.runtime).to_string();
+    let genre = or_unknown(&movie.genre).to_string();
+    let rating_runtime = format!("Rating: {} | Runtime: {}", rated, runtime);
+    let genre_fits_inline = rating_runtime.width() + " | Genre: ".width() + genre.width() <= width;
+
+    let mut info = vec![
+        Span::styled("Rating: ", label_style),
+        Span::styled(rated, value_style),
+        Span::raw(" | "),
+        Span::styled("Runtime: ", label_style),
+        Span::styled(runtime, value_style),
+    ];
+    let genre_spans = |available: usize| {
+        vec![
+            Span::styled("Genre: ", label_style),
+            Span::styled(truncate_to_width(&genre, available), value_style),
+        ]
+    };
+
+    if genre_fits_inline {
+        info.push(Span::raw(" | "));
+        info.extend(genre_spans(width));
+        vec![heading, Line::from(info)]
+    } else {
+        let genre_line = Line::from(genre_spans(width.saturating_sub("Genre: ".width())));
+        vec![heading, Line::from(info), genre_line]
+    }
+}
+
+/// Renders the movie's upcoming sessions grouped by day, with the selected one highlighted
+fn render_sessions_section(
+    frame: &mut Frame,
+    area: Rect,
+    sessions: &[(DateTime<Local>, SessionInfo)],
+    selected: usize,
+    config: &Config,
+    focused: bool,
+) {
+    let block = if focused {
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title("Sessions (↑↓ select, Enter to book, e to export)")
+    } else {
+        Block::default()
+            .borders(Borders::TOP)
+            .title("Sessions (←→ select, Enter to book)")
+    };
+
+    let line = if sessions.is_empty() {
+        Line::from(Span::styled(
+            "No upcoming sessions",
+            Style::default().fg(Color::Gray),
+        ))
+    } else {
+        // Grouped by day: each day's date is shown once, before its first time
+        let mut spans = Vec::new();
+        let mut current_day = None;
+        for (i, (time, info)) in sessions.iter().enumerate() {
+            if current_day != Some(time.date_naive()) {
+                current_day = Some(time.date_naive());
+                let separator = if i > 0 { "   " } else { "" };
+                spans.push(Span::styled(
+                    format!("{}{}: ", separator, time.format(config.date_format.short())),
+                    Style::default().fg(theme(config).accent),
+                ));
+            } else {
+                spans.push(Span::raw("  "));
+            }
+
+            let style = if i == selected {
+                theme(config).highlight()
+            } else if info.booking_url.is_some() {
+                Style::default().fg(Color::White)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+
+            spans.push(Span::styled(format_showtime(time, config.use_24h), style));
+        }
+        Line::from(spans)
+    };
+
+    let paragraph = Paragraph::new(line)
+        .block(block)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Renders how one day's sessions spread through it: a bar per hour from the first session's
+/// hour to the last's, scaled to the busiest hour, over the first and last session times
+fn render_timeline_section(frame: &mut Frame, area: Rect, times: &[DateTime<Local>], config: &Config) {
+    const BAR_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let (Some(first), Some(last)) = (times.iter().min(), times.iter().max()) else {
+        return;
+    };
+    let theme = theme(config);
+    let opening = first.hour() as usize;
+    let mut counts = vec![0; last.hour() as usize - opening + 1];
+    for time in times {
+        counts[time.hour() as usize - opening] += 1;
+    }
+    let busiest = counts.iter().copied().max().unwrap_or(1);
+
+    // Up to three columns an hour, one of them a gap between bars
+    let cell = (area.width as usize / counts.len()).clamp(1, 3);
+    let bar_width = cell.saturating_sub(1).max(1);
+    let gap = " ".repeat(cell - bar_width);
+    let bars: Vec<Span> = counts
+        .iter()
+        .map(|&count| {
+            if count == 0 {
+                Span::styled(format!("{:<cell$}", "·"), Style::default().fg(theme.muted))
+            } else {
+                let level = BAR_LEVELS[(count * BAR_LEVELS.len()).div_ceil(busiest) - 1];
+                Span::styled(
+                    format!("{}{}", level.to_string().repeat(bar_width), gap),
+                    Style::default().fg(theme.accent),
+                )
+            }
+        })
+        .collect();
+
+    // The closing time sits under the last bar when both labels fit across the bars
+    let open_label = format_showtime(first, config.use_24h);
+    let close_label = format_showtime(last, config.use_24h);
+    let axis = if first == last {
+        open_label
+    } else {
+        let room = (counts.len() * cell).saturating_sub(open_label.width() + close_label.width());
+        if room > 0 {
+            format!("{}{}{}", open_label, " ".repeat(room), close_label)
+        } else {
+            format!("{} - {}", open_label, close_label)
+        }
+    };
+
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .title(format!("Sessions by hour - {}", first.format(config.date_format.short())));
+    let lines = vec![
+        Line::from(bars),
+        Line::from(Span::styled(axis, Style::default().fg(theme.muted))),
     ];
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// The first line to show so the content's end fills the last `height` rows at `width`
+fn last_page_start(content: &[Line], width: usize, height: usize) -> usize {
+    let mut rows = 0;
+    for (i, line) in content.iter().enumerate().rev() {
+        rows += wrap_to_width(&line_text(line), width).len();
+        // A single line taller than the area still gets shown from its top
+        if rows > height {
+            return (i + 1).min(content.len() - 1);
+        }
+    }
+    0
+}
 
-    let title_paragraph = Paragraph::new(title_info);
-    frame.render_widget(title_paragraph, area);
+/// Flattens a line's spans into plain text for searching
+fn line_text(line: &Line) -> String {
+    line.spans.iter().map(|span| span.content.as_ref()).collect()
 }
 
-/// Renders the main content section
-fn render_content_section(frame: &mut Frame, area: Rect, movie: &crate::app::omd::Welcome) {
-    let mut content = vec![];
+/// Builds the main content section (plot, credits, ratings and extra info)
+fn content_lines<'a>(movie: &'a crate::app::omd::Welcome, config: &Config) -> Vec<Line<'a>> {
+    let palette = palette(config);
+
+    let mut content = Vec::new();
 
     // Plot
-    content.push(Line::from(Span::styled(
-        "Plot:",
-        Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
-    )));
-    content.push(Line::from(Span::styled(
-        &movie.plot,
-        Style::default().fg(Color::White),
-    )));
-    content.push(Line::from(""));
+    if is_known(&movie.plot) {
+        content.push(Line::from(Span::styled(
+            "Plot:",
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )));
+        content.push(Line::from(Span::styled(
+            &movie.plot,
+            Style::default().fg(Color::White),
+        )));
+        content.push(Line::from(""));
+    }
 
-    // Director
-    content.push(Line::from(vec![
-        Span::styled("Director: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Span::styled(&movie.director, Style::default().fg(Color::White)),
-    ]));
-
-    // Writer
-    content.push(Line::from(vec![
-        Span::styled("Writer: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Span::styled(&movie.writer, Style::default().fg(Color::White)),
-    ]));
-
-    // Actors
-    content.push(Line::from(vec![
-        Span::styled("Actors: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-        Span::styled(&movie.actors, Style::default().fg(Color::White)),
-    ]));
+    // Credits, skipping any OMDb doesn't know
+    for (label, value) in [
+        ("Director: ", &movie.director),
+        ("Writer: ", &movie.writer),
+        ("Actors: ", &movie.actors),
+    ] {
+        if is_known(value) {
+            content.push(Line::from(vec![
+                Span::styled(label, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(value, Style::default().fg(Color::White)),
+            ]));
+        }
+    }
     content.push(Line::from(""));
 
     // Ratings
@@ -337,61 +715,131 @@ fn render_content_section(frame: &mut Frame, area: Rect, movie: &crate::app::omd
     )));
 
     // IMDb Rating
-    if movie.imdb_rating != "N/A" {
-        let rating_color = get_rating_color(&movie.imdb_rating);
+    if is_known(&movie.imdb_rating) {
+        let (rating_color, symbol) = get_rating_color(&movie.imdb_rating, &palette);
         content.push(Line::from(vec![
-            Span::raw("  IMDb: "),
-            Span::styled(&movie.imdb_rating, Style::default().fg(rating_color).add_modifier(Modifier::BOLD)),
-            Span::styled(format!(" ({} votes)", movie.imdb_votes), Style::default().fg(Color::Gray)),
+            Span::raw(rating_label("Internet Movie Database", "IMDb", config.rating_icons)),
+            Span::styled(
+                format!("{}{}", movie.imdb_rating, symbol),
+                Style::default().fg(rating_color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                if is_known(&movie.imdb_votes) { format!(" ({} votes)", movie.imdb_votes) } else { String::new() },
+                Style::default().fg(Color::Gray),
+            ),
+            stars_span(&movie.imdb_rating, 10.0, config.rating_stars),
         ]));
     }
 
     // Metascore
-    if movie.metascore != "N/A" {
-        let rating_color = get_metascore_color(&movie.metascore);
+    if is_known(&movie.metascore) {
+        let (rating_color, symbol) = get_metascore_color(&movie.metascore, &palette);
         content.push(Line::from(vec![
-            Span::raw("  Metascore: "),
-            Span::styled(&movie.metascore, Style::default().fg(rating_color).add_modifier(Modifier::BOLD)),
+            Span::raw(rating_label("Metacritic", "Metascore", config.rating_icons)),
+            Span::styled(
+                format!("{}{}", movie.metascore, symbol),
+                Style::default().fg(rating_color).add_modifier(Modifier::BOLD),
+            ),
         ]));
     }
 
     // Other ratings
     for rating in &movie.ratings {
+        let stars = if rating.source == "Rotten Tomatoes" {
+            stars_span(rating.value.trim_end_matches('%'), 100.0, config.rating_stars)
+        } else {
+            Span::raw("")
+        };
+        if !is_known(&rating.value) {
+            continue;
+        }
         content.push(Line::from(vec![
-            Span::raw(format!("  {}: ", rating.source)),
+            Span::raw(rating_label(&rating.source, &rating.source, config.rating_icons)),
             Span::styled(&rating.value, Style::default().fg(Color::Yellow)),
+            stars,
         ]));
     }
 
     content.push(Line::from(""));
 
     // Additional info
-    if movie.awards != "N/A" {
+    if is_known(&movie.awards) {
         content.push(Line::from(vec![
             Span::styled("Awards: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
             Span::styled(&movie.awards, Style::default().fg(Color::Yellow)),
         ]));
     }
 
-    if movie.box_office != "N/A" {
+    if is_known(&movie.box_office) {
         content.push(Line::from(vec![
             Span::styled("Box Office: ", Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
             Span::styled(&movie.box_office, Style::default().fg(Color::White)),
         ]));
     }
 
-    content.push(Line::from(vec![
-        Span::styled("Language: ", Style::default().fg(Color::Gray)),
-        Span::styled(&movie.language, Style::default().fg(Color::White)),
-        Span::raw(" | "),
-        Span::styled("Country: ", Style::default().fg(Color::Gray)),
-        Span::styled(&movie.country, Style::default().fg(Color::White)),
-    ]));
+    // Release dates, each only when OMDb has one
+    for (label, value) in [("Released: ", &movie.released), ("Home release: ", &movie.dvd)] {
+        if is_known(value) {
+            content.push(Line::from(vec![
+                Span::styled(label, Style::default().fg(Color::Gray)),
+                Span::styled(value, Style::default().fg(Color::White)),
+            ]));
+        }
+    }
 
-    let content_paragraph = Paragraph::new(content)
-        .wrap(Wrap { trim: true });
+    if is_known(&movie.language) || is_known(&movie.country) {
+        content.push(Line::from(vec![
+            Span::styled("Language: ", Style::default().fg(Color::Gray)),
+            Span::styled(or_unknown(&movie.language), Style::default().fg(Color::White)),
+            Span::raw(" | "),
+            Span::styled("Country: ", Style::default().fg(Color::Gray)),
+            Span::styled(or_unknown(&movie.country), Style::default().fg(Color::White)),
+        ]));
+    }
+
+    content
+}
+
+/// Lists other screening movies with overlapping genres, numbered for opening with 1-5
+fn similar_lines(similar: &[(String, Vec<String>)]) -> Vec<Line<'static>> {
+    if similar.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Similar now showing:",
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )),
+    ];
+    for (i, (name, shared)) in similar.iter().enumerate() {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  ({}) ", i + 1), Style::default().fg(Color::Yellow)),
+            Span::styled(name.clone(), Style::default().fg(Color::White)),
+            Span::styled(format!(" - {}", shared.join(", ")), Style::default().fg(Color::Gray)),
+        ]));
+    }
+    lines
+}
 
-    frame.render_widget(content_paragraph, area);
+/// Builds the cinema's name and address with the key for directions
+fn cinema_lines(config: &Config) -> Vec<Line<'static>> {
+    vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Cinema:",
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!("  {}", config.cinema_location()),
+            Style::default().fg(Color::White),
+        )),
+        Line::from(Span::styled(
+            "  (d) get directions",
+            Style::default().fg(Color::Gray),
+        )),
+    ]
 }
 
 /// Renders empty state
@@ -421,32 +869,129 @@ fn render_empty_state(frame: &mut Frame, area: Rect) {
     frame.render_widget(empty_paragraph, area);
 }
 
+/// Whether OMDb actually has a value for a field, rather than "N/A" or nothing
+fn is_known(value: &str) -> bool {
+    known_value(value).is_some()
+}
+
+/// The field's value, or a dash when OMDb doesn't know it
+fn or_unknown(value: &str) -> &str {
+    if is_known(value) { value } else { "—" }
+}
+
+/// Builds the indented "Label: " prefix for a rating row, with an optional source icon
+fn rating_label(source: &str, label: &str, icons: bool) -> String {
+    let icon = match source {
+        "Internet Movie Database" => Some("★"),
+        "Rotten Tomatoes" => Some("🍅"),
+        "Metacritic" => Some("Ⓜ"),
+        _ => None,
+    };
+
+    match icon {
+        Some(icon) if icons => format!("  {} {}: ", icon, label),
+        _ => format!("  {}: ", label),
+    }
+}
+
+/// Draws a score out of `max` as five stars, rounded to the nearest half
+fn star_bar(score: f32, max: f32) -> String {
+    let halves = ((score / max).clamp(0.0, 1.0) * 10.0).round() as usize;
+    let full = halves / 2;
+    let half = halves % 2;
+    let empty = 5 - full - half;
+    format!("{}{}{}", "★".repeat(full), "⯪".repeat(half), "☆".repeat(empty))
+}
+
+/// Star glyphs for a numeric score, or nothing when disabled or the score isn't a number
+fn stars_span(score: &str, max: f32, enabled: bool) -> Span<'static> {
+    match score.parse::<f32>() {
+        Ok(score) if enabled => Span::styled(
+            format!("  {}", star_bar(score, max)),
+            Style::default().fg(Color::Yellow),
+        ),
+        _ => Span::raw(""),
+    }
+}
+
+/// Color and shape cue for a score, or plain white when it isn't a number
+fn level_style(level: Option<Level>, palette: &Palette) -> (Color, &'static str) {
+    match level {
+        Some(level) => (palette.level(level), palette.level_symbol(level)),
+        None => (Color::White, ""),
+    }
+}
+
 /// Helper function to get color based on IMDb rating
-fn get_rating_color(rating: &str) -> Color {
-    if let Ok(score) = rating.parse::<f32>() {
+fn get_rating_color(rating: &str, palette: &Palette) -> (Color, &'static str) {
+    let level = rating.parse::<f32>().ok().map(|score| {
         if score >= 7.0 {
-            Color::Green
+            Level::Good
         } else if score >= 5.0 {
-            Color::Yellow
+            Level::Fair
         } else {
-            Color::Red
+            Level::Poor
         }
-    } else {
-        Color::White
-    }
+    });
+    level_style(level, palette)
 }
 
 /// Helper function to get color based on Metascore
-fn get_metascore_color(score: &str) -> Color {
-    if let Ok(score_val) = score.parse::<i32>() {
+fn get_metascore_color(score: &str, palette: &Palette) -> (Color, &'static str) {
+    let level = score.parse::<i32>().ok().map(|score_val| {
         if score_val >= 70 {
-            Color::Green
+            Level::Good
         } else if score_val >= 50 {
-            Color::Yellow
+            Level::Fair
         } else {
-            Color::Red
+            Level::Poor
         }
-    } else {
-        Color::White
+    });
+    level_style(level, palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::omd::Welcome;
+
+    fn movie(title: &str, genre: &str) -> Welcome {
+        Welcome {
+            year: "2024".to_string(),
+            rated: "PG-13".to_string(),
+            runtime: "160 min".to_string(),
+            genre: genre.to_string(),
+            ..Welcome::unknown(title)
+        }
+    }
+
+    #[test]
+    fn short_genre_stays_beside_the_rating() {
+        let lines = title_lines(&movie("Wicked", "Musical"), 80);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].width() <= 80);
+    }
+
+    #[test]
+    fn long_genre_moves_to_its_own_line_within_the_width() {
+        let genre = "Action, Adventure, Comedy, Drama, Fantasy, Musical, Romance, Sci-Fi, Thriller";
+        let width = 40;
+        let lines = title_lines(&movie("Wicked", genre), width);
+
+        assert_eq!(lines.len(), 3);
+        for line in &lines {
+            assert!(line.width() <= width, "{:?} is wider than {}", line_text(line), width);
+        }
+        let genre_line = line_text(&lines[2]);
+        assert!(genre_line.starts_with("Genre: Action"));
+        assert!(genre_line.ends_with('…'), "{:?}", genre_line);
+    }
+
+    #[test]
+    fn long_title_is_cut_to_leave_room_for_the_year() {
+        let title = "A Title Far Too Long To Fit On One Narrow Line";
+        let lines = title_lines(&movie(title, "Drama"), 30);
+        assert!(lines[0].width() <= 30);
+        assert!(line_text(&lines[0]).ends_with(" (2024)"));
     }
 }
@@ -0,0 +1,84 @@
+use crate::app::App;
+use crate::app::overview::{OverviewColumn, build_overview};
+use super::theme::theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+};
+
+/// Renders every cached movie and its schedule as a sortable table
+pub fn render_overview(frame: &mut Frame, app: &mut App, area: Rect) {
+    let block = Block::default()
+        .title("All Cached Showtimes")
+        .borders(Borders::ALL);
+
+    let rows = build_overview(
+        &app.ritz_movie_times,
+        app.overview_sort,
+        app.overview_descending,
+    );
+    if rows.is_empty() {
+        let paragraph = Paragraph::new(Span::styled(
+            "No showtimes loaded - press (g) on the main screen to load",
+            Style::default().fg(Color::Gray),
+        ))
+        .block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    // Mark the sorted column with its direction
+    let header = Row::new(OverviewColumn::ALL.map(|column| {
+        let label = if column == app.overview_sort {
+            let arrow = if app.overview_descending { "▼" } else { "▲" };
+            format!("{} {}", column.label(), arrow)
+        } else {
+            column.label().to_string()
+        };
+        Cell::from(label)
+    }))
+    .style(
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let table_rows: Vec<Row> = rows
+        .iter()
+        .map(|row| {
+            let dates = row
+                .dates
+                .iter()
+                .map(|date| date.format("%a %d").to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            Row::new(vec![
+                Cell::from(row.name.clone()),
+                Cell::from(dates),
+                Cell::from(row.sessions.to_string()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        table_rows,
+        [
+            Constraint::Percentage(40),
+            Constraint::Percentage(50),
+            Constraint::Percentage(10),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .row_highlight_style(
+        Style::default()
+            .bg(theme(&app.config).highlight_bg)
+            .add_modifier(Modifier::BOLD),
+    )
+    .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(table, area, &mut app.overview_state);
+}
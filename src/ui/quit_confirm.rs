@@ -0,0 +1,34 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Draws the quit confirmation centered over whatever screen it was opened from
+pub fn render_quit_confirm(frame: &mut Frame, area: Rect) {
+    render_confirm(frame, area, "Quit cinema_tui? (y/n)");
+}
+
+/// Draws a yes/no question in a bordered box centered over `area`
+pub fn render_confirm(frame: &mut Frame, area: Rect, question: &str) {
+    let [popup_area] = Layout::vertical([Constraint::Length(3)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup_area] = Layout::horizontal([Constraint::Length(question.width() as u16 + 6)])
+        .flex(Flex::Center)
+        .areas(popup_area);
+
+    let popup = Paragraph::new(question)
+        .alignment(Alignment::Center)
+        .style(Style::default().add_modifier(Modifier::BOLD))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        );
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}
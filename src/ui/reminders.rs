@@ -0,0 +1,92 @@
+use crate::app::App;
+use crate::app::upcoming::format_countdown;
+use crate::app::config::format_showtime;
+use chrono::Local;
+use super::theme::theme;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+
+/// Renders the queued session reminders, soonest to fire first
+pub fn render_reminders(frame: &mut Frame, app: &mut App, area: Rect) {
+    let block = Block::default()
+        .title(format!("Reminders ({})", app.state.reminders.len()))
+        .borders(Borders::ALL);
+
+    if app.state.reminders.is_empty() {
+        let paragraph = Paragraph::new(Span::styled(
+            "No reminders set - press (r) in a session popup or (a) on the detail screen to add one",
+            Style::default().fg(Color::Gray),
+        ))
+        .block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let now = Local::now();
+    let date_format = app.config.date_format.short();
+    let items: Vec<ListItem> = app
+        .state
+        .reminders
+        .iter()
+        .map(|reminder| {
+            let fires_at = reminder.fires_at();
+            let fires = if reminder.fired {
+                Span::styled(
+                    format!(
+                        "{:<24}",
+                        format!("fired at {}", format_showtime(&fires_at, app.config.use_24h))
+                    ),
+                    Style::default().fg(Color::Gray),
+                )
+            } else {
+                Span::styled(
+                    format!(
+                        "{:<24}",
+                        format!(
+                            "fires {} {}",
+                            format_showtime(&fires_at, app.config.use_24h),
+                            format_countdown(now, fires_at)
+                        )
+                    ),
+                    Style::default().fg(Color::Yellow),
+                )
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!(
+                        "{} {:>8}  ",
+                        reminder.session.format(date_format),
+                        format_showtime(&reminder.session, app.config.use_24h)
+                    ),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    format!("{:>3}m before  ", reminder.lead_minutes),
+                    Style::default().fg(Color::Gray),
+                ),
+                fires,
+                Span::styled(
+                    reminder.movie.clone(),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(
+            theme(&app.config).highlight(),
+        )
+        .highlight_symbol("▶ ");
+
+    frame.render_stateful_widget(list, area, &mut app.reminder_state);
+}
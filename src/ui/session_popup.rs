@@ -0,0 +1,88 @@
+use crate::app::App;
+use crate::app::ical::parse_runtime_minutes;
+use crate::app::config::format_showtime;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// Renders a panel over the list with the focused session's details and booking action
+pub fn render_session_popup(frame: &mut Frame, app: &App, area: Rect) {
+    let Some((name, start, info)) = app.get_popup_session() else {
+        return;
+    };
+
+    let label_style = Style::default().fg(Color::Gray);
+    let value_style = Style::default().fg(Color::White);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            name.clone(),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Date:    ", label_style),
+            Span::styled(
+                start.format(app.config.date_format.long()).to_string(),
+                value_style,
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Starts:  ", label_style),
+            Span::styled(format_showtime(&start, app.config.use_24h), value_style),
+        ]),
+    ];
+
+    // The end time is only known once the movie's runtime has been fetched from OMDb
+    let runtime = app
+        .detail_cache
+        .get(&name)
+        .and_then(|details| parse_runtime_minutes(&details.runtime));
+    if let Some(minutes) = runtime {
+        let end = start + chrono::Duration::minutes(minutes);
+        lines.push(Line::from(vec![
+            Span::styled("Ends:    ", label_style),
+            Span::styled(
+                format!("{} ({} min)", format_showtime(&end, app.config.use_24h), minutes),
+                value_style,
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("Booking: ", label_style),
+        match &info.booking_url {
+            Some(url) => Span::styled(url.clone(), Style::default().fg(Color::Cyan)),
+            None => Span::styled("No booking link found", label_style),
+        },
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "(Enter) book, (r) remind me, (Esc/b) close",
+        label_style,
+    )));
+
+    let height = lines.len() as u16 + 2;
+    let [popup_area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup_area] = Layout::horizontal([Constraint::Percentage(70)])
+        .flex(Flex::Center)
+        .areas(popup_area);
+
+    let popup = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title("Session")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}
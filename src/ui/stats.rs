@@ -0,0 +1,88 @@
+use crate::app::App;
+use crate::app::config::format_showtime;
+use crate::app::stats::{compute_stats, weekday};
+use chrono::{DateTime, Local};
+use super::theme::theme;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Widest a weekday's bar gets, for the busiest day
+const BAR_WIDTH: usize = 40;
+
+/// Renders totals for everything loaded, with sessions per weekday as a bar list
+pub fn render_stats(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = theme(&app.config);
+    let block = Block::default().title("Stats").borders(Borders::ALL);
+
+    let stats = compute_stats(&app.ritz_movie_times);
+    if stats.showtimes == 0 {
+        let paragraph = Paragraph::new(Span::styled(
+            "No showtimes loaded - press (g) on the main screen to load",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let label_style = Style::default().fg(theme.muted);
+    let value_style = Style::default().fg(theme.text).add_modifier(Modifier::BOLD);
+    let row = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("  {:<22}", label), label_style),
+            Span::styled(value, value_style),
+        ])
+    };
+    let session = |session: &Option<(String, DateTime<Local>)>| match session {
+        Some((name, time)) => format!(
+            "{} - {} ({})",
+            format_showtime(time, app.config.use_24h),
+            name,
+            time.format(app.config.date_format.short())
+        ),
+        None => "-".to_string(),
+    };
+    let heading = |text: &'static str| {
+        Line::from(Span::styled(
+            text,
+            Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+        ))
+    };
+
+    let mut lines = vec![
+        heading("Loaded showtimes"),
+        row("Movies", stats.movies.to_string()),
+        row("Showtimes", stats.showtimes.to_string()),
+        row("Days", stats.days.to_string()),
+        row(
+            "Busiest weekday",
+            stats
+                .busiest_weekday()
+                .map(|(day, count)| format!("{} ({} sessions)", day, count))
+                .unwrap_or_default(),
+        ),
+        row("Earliest screening", session(&stats.earliest)),
+        row("Latest screening", session(&stats.latest)),
+        row("Showing every day", stats.every_day.to_string()),
+        row("Showing on one day", stats.one_day.to_string()),
+        Line::default(),
+        heading("Sessions by weekday"),
+    ];
+
+    let max_count = stats.weekday_sessions.iter().copied().max().unwrap_or(0).max(1);
+    for (index, count) in stats.weekday_sessions.iter().enumerate() {
+        let bar = "█".repeat((count * BAR_WIDTH).div_ceil(max_count));
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<5}", weekday(index)), label_style),
+            Span::styled(bar, Style::default().fg(theme.accent)),
+            Span::styled(format!(" {}", count), value_style),
+        ]));
+    }
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
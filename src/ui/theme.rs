@@ -0,0 +1,192 @@
+use crate::app::config::{ColorTheme, Config};
+use ratatui::style::{Color, Modifier, Style};
+use std::str::FromStr;
+
+/// How good a score or outcome is, for coloring it consistently across screens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Level {
+    Good,
+    Fair,
+    Poor,
+}
+
+/// Colors for everything that carries meaning, so the red/green defaults can be swapped out
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub good: Color,
+    pub fair: Color,
+    pub poor: Color,
+    pub error: Color,
+    pub success: Color,
+    /// Heatmap backgrounds from quietest to busiest
+    pub heat: [Color; 5],
+    /// Whether to pair colors with symbols, so meaning never depends on color alone
+    pub symbols: bool,
+}
+
+/// The usual red/yellow/green palette
+const STANDARD: Palette = Palette {
+    good: Color::Green,
+    fair: Color::Yellow,
+    poor: Color::Red,
+    error: Color::Red,
+    success: Color::Green,
+    heat: [
+        Color::DarkGray,
+        Color::Blue,
+        Color::Green,
+        Color::Yellow,
+        Color::Red,
+    ],
+    symbols: false,
+};
+
+/// Blue/orange palette built from the Okabe-Ito colors, which stay distinct for all common color vision deficiencies
+const COLORBLIND: Palette = Palette {
+    good: Color::Rgb(86, 180, 233),
+    fair: Color::Rgb(240, 228, 66),
+    poor: Color::Rgb(230, 159, 0),
+    error: Color::Rgb(230, 159, 0),
+    success: Color::Rgb(86, 180, 233),
+    heat: [
+        Color::DarkGray,
+        Color::Rgb(0, 114, 178),
+        Color::Rgb(86, 180, 233),
+        Color::Rgb(240, 228, 66),
+        Color::Rgb(230, 159, 0),
+    ],
+    symbols: true,
+};
+
+impl Palette {
+    pub fn level(&self, level: Level) -> Color {
+        match level {
+            Level::Good => self.good,
+            Level::Fair => self.fair,
+            Level::Poor => self.poor,
+        }
+    }
+
+    /// Shape cue shown beside a colored score, or nothing when symbols are off
+    pub fn level_symbol(&self, level: Level) -> &'static str {
+        match (self.symbols, level) {
+            (false, _) => "",
+            (true, Level::Good) => " ▲",
+            (true, Level::Fair) => " ●",
+            (true, Level::Poor) => " ▼",
+        }
+    }
+}
+
+/// The semantic colors the screens are drawn with, so they can suit dark or light terminals
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    /// Titles, popup borders and the selected row's text
+    pub accent: Color,
+    /// Background of the selected row
+    pub highlight_bg: Color,
+    /// The header's title text
+    pub header: Color,
+    /// Movie titles and other primary text
+    pub text: Color,
+    /// Labels, hints and other secondary text
+    pub muted: Color,
+    pub palette: Palette,
+}
+
+/// The original look, made for dark terminal backgrounds
+const DARK: Theme = Theme {
+    accent: Color::Yellow,
+    highlight_bg: Color::DarkGray,
+    header: Color::Reset,
+    text: Color::White,
+    muted: Color::Gray,
+    palette: STANDARD,
+};
+
+/// Darker text on a pale highlight; yellow and white would wash out on a light background
+const LIGHT: Theme = Theme {
+    accent: Color::Blue,
+    highlight_bg: Color::Gray,
+    header: Color::Black,
+    text: Color::Black,
+    muted: Color::DarkGray,
+    palette: STANDARD,
+};
+
+/// A yellow dark enough to read on a light background, for middling ratings
+const LIGHT_FAIR: Color = Color::Rgb(184, 134, 11);
+
+/// The theme selected in the config, with the colorblind palette and any color overrides applied
+pub fn theme(config: &Config) -> Theme {
+    let mut theme = match config.theme {
+        ColorTheme::Dark => DARK,
+        ColorTheme::Light => LIGHT,
+    };
+    if config.colorblind_palette {
+        theme.palette = COLORBLIND;
+    }
+    if config.theme == ColorTheme::Light {
+        theme.palette.fair = LIGHT_FAIR;
+    }
+
+    let overrides = &config.theme_colors;
+    let apply = |value: &Option<String>, color: &mut Color| {
+        if let Some(value) = value {
+            *color = config_color(value, *color);
+        }
+    };
+    apply(&overrides.accent, &mut theme.accent);
+    apply(&overrides.highlight_background, &mut theme.highlight_bg);
+    apply(&overrides.header, &mut theme.header);
+    apply(&overrides.text, &mut theme.text);
+    apply(&overrides.muted, &mut theme.muted);
+    apply(&overrides.rating_good, &mut theme.palette.good);
+    apply(&overrides.rating_fair, &mut theme.palette.fair);
+    apply(&overrides.rating_poor, &mut theme.palette.poor);
+    theme
+}
+
+/// The palette of the configured theme
+pub fn palette(config: &Config) -> Palette {
+    theme(config).palette
+}
+
+impl Theme {
+    /// The selected row in a list: accent text on the highlight background
+    pub fn highlight(&self) -> Style {
+        Style::default()
+            .bg(self.highlight_bg)
+            .fg(self.accent)
+            .add_modifier(Modifier::BOLD)
+    }
+}
+
+/// Styles for the date header, built from the configured colors
+pub struct DateStyles {
+    pub today: Style,
+    pub selected: Style,
+    /// Selected and today at once: the selected colors underlined in the today color
+    pub selected_today: Style,
+}
+
+/// Parses a configured color, falling back to `default` for names ratatui doesn't know
+fn config_color(value: &str, default: Color) -> Color {
+    Color::from_str(value.trim()).unwrap_or(default)
+}
+
+pub fn date_styles(config: &Config) -> DateStyles {
+    let today = config_color(&config.today_color, Color::Cyan);
+    let selected = Style::default()
+        .fg(config_color(&config.selected_date_color, Color::Yellow))
+        .bg(config_color(&config.selected_date_background, Color::DarkGray))
+        .add_modifier(Modifier::BOLD);
+
+    DateStyles {
+        today: Style::default().fg(today).add_modifier(Modifier::BOLD),
+        selected,
+        selected_today: selected
+            .underline_color(today)
+            .add_modifier(Modifier::UNDERLINED),
+    }
+}
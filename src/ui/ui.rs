@@ -1,17 +1,42 @@
 use crate::app::{App, CurrentScreen};
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Wrap},
 };
 
+use super::banner::{
+    render_alert_banner, render_error_banner, render_stale_banner, render_warning_banner,
+};
+use super::diff::render_refresh_diff;
+use super::double_feature::render_double_features;
 use super::footer::render_footer;
 use super::header::render_header;
+use super::heatmap::render_heatmap;
+use super::help::render_help;
+use super::genre_popup::render_genre_popup;
+use super::jump_palette::render_jump_palette;
 use super::loading::render_loading;
 use super::main_content::render_main_content;
 use super::movie_detail::render_movie_detail;
+use super::overview::render_overview;
+use super::quit_confirm::{render_confirm, render_quit_confirm};
+use super::reminders::render_reminders;
+use super::stats::render_stats;
+use super::session_popup::render_session_popup;
+use super::upcoming::render_upcoming;
+use super::week::{render_week, week_fits};
 
 /// Main UI rendering function that orchestrates all UI components
 pub fn ui(frame: &mut Frame, app: &mut App) {
+    let area = frame.area();
+    if area.width < app.config.min_terminal_width || area.height < app.config.min_terminal_height {
+        render_too_small(frame, app);
+        return;
+    }
+
     // Create the main layout: header, content area, footer
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -20,26 +45,127 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
             Constraint::Min(1),     // Content
             Constraint::Length(3),  // Footer
         ])
-        .split(frame.area());
+        .split(area);
 
     // Render header
     render_header(frame, app, chunks[0]);
 
-    // Render content based on current screen
-    match app.current_screen {
+    // Render content based on current screen, or the one a quit confirmation was opened from
+    match app.visible_screen() {
         CurrentScreen::MovieDetail => {
             render_movie_detail(frame, app, chunks[1]);
         }
+        CurrentScreen::RefreshDiff => {
+            render_refresh_diff(frame, app, chunks[1]);
+        }
+        CurrentScreen::Heatmap => {
+            render_heatmap(frame, app, chunks[1]);
+        }
+        CurrentScreen::Overview => {
+            render_overview(frame, app, chunks[1]);
+        }
+        CurrentScreen::Upcoming => {
+            render_upcoming(frame, app, chunks[1]);
+        }
+        CurrentScreen::DoubleFeature => {
+            render_double_features(frame, app, chunks[1]);
+        }
+        CurrentScreen::Reminders => {
+            render_reminders(frame, app, chunks[1]);
+        }
+        CurrentScreen::Stats => {
+            render_stats(frame, app, chunks[1]);
+        }
         _ => {
+            // Make room for the error, favorite, scrape-warning or stale-data banner above the list
+            let error = app.get_error().filter(|_| !app.loading_movies);
+            let alert = app.imminent_alert_text().filter(|_| !app.loading_movies);
+            let show_warning = !app.scrape_warnings.is_empty() && !app.loading_movies;
+            let show_banner =
+                error.is_some() || alert.is_some() || show_warning || app.show_stale_banner;
+            let content_area = if show_banner {
+                let content_chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(3), Constraint::Min(1)])
+                    .split(chunks[1]);
+                if let Some(error) = error {
+                    render_error_banner(frame, error, content_chunks[0]);
+                } else if let Some(alert) = &alert {
+                    render_alert_banner(frame, app, alert, content_chunks[0]);
+                } else if show_warning {
+                    render_warning_banner(frame, app, content_chunks[0]);
+                } else {
+                    render_stale_banner(frame, app, content_chunks[0]);
+                }
+                content_chunks[1]
+            } else {
+                chunks[1]
+            };
+
             // Render main content area (loading screen or movie list)
             if app.loading_movies {
-                render_loading(frame, app, chunks[1]);
+                render_loading(frame, app, content_area);
             } else {
-                render_main_content(frame, app, chunks[1]);
+                if app.week_view && week_fits(app, content_area) {
+                    render_week(frame, app, content_area);
+                } else {
+                    render_main_content(frame, app, content_area);
+                }
+                if app.session_popup {
+                    render_session_popup(frame, app, content_area);
+                }
+                if app.jump_palette {
+                    render_jump_palette(frame, app, content_area);
+                }
+                if app.genre_popup {
+                    render_genre_popup(frame, app, content_area);
+                }
             }
         }
     }
 
+    // Help goes over everything else on the current screen
+    if app.show_help {
+        render_help(frame, app, chunks[1]);
+    }
+
+    if app.confirm_clear_cache {
+        render_confirm(
+            frame,
+            chunks[1],
+            "Delete cached showtimes, details, posters and favorites? (y/n)",
+        );
+    }
+
+    if app.current_screen == CurrentScreen::Exiting {
+        render_quit_confirm(frame, chunks[1]);
+    }
+
     // Render footer with instructions
     render_footer(frame, app, chunks[2]);
 }
+
+/// Replaces the whole UI with a size warning when the fixed-height layouts wouldn't fit
+fn render_too_small(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+    let message = vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(
+            "Need at least {}x{}, have {}x{}",
+            app.config.min_terminal_width, app.config.min_terminal_height, area.width, area.height
+        )),
+        Line::from(if app.current_screen == CurrentScreen::Exiting {
+            "Quit cinema_tui? (y/n)"
+        } else {
+            "(q) to quit"
+        }),
+    ];
+
+    let paragraph = Paragraph::new(message)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(paragraph, area);
+}
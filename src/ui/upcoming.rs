@@ -0,0 +1,59 @@
+use crate::app::App;
+use crate::app::upcoming::{format_countdown, sessions_within};
+use crate::app::config::format_showtime;
+use chrono::Local;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+
+/// Renders today's sessions starting within the chosen window, soonest first
+pub fn render_upcoming(frame: &mut Frame, app: &App, area: Rect) {
+    let hours = app.upcoming_window_hours;
+    let block = Block::default()
+        .title(format!(
+            "Starting in the next {} hour{}",
+            hours,
+            if hours == 1 { "" } else { "s" }
+        ))
+        .borders(Borders::ALL);
+
+    let now = Local::now();
+    let sessions = sessions_within(&app.ritz_movie_times, now, hours);
+    if sessions.is_empty() {
+        let paragraph = Paragraph::new(Span::styled(
+            "No sessions start in this window - press (+) to look further ahead",
+            Style::default().fg(Color::Gray),
+        ))
+        .block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = sessions
+        .iter()
+        .map(|(name, time)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:>8}  ", format_showtime(time, app.config.use_24h)),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::styled(
+                    format!("{:<10}", format_countdown(now, *time)),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    name.clone(),
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(List::new(items).block(block), area);
+}
@@ -0,0 +1,90 @@
+use crate::app::{App, ClickTargets};
+use crate::app::config::format_showtime;
+use super::text::truncate_to_width;
+use super::theme::theme;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+};
+
+/// Narrowest a day column can be and still fit a readable title and a couple of times
+const MIN_COLUMN_WIDTH: u16 = 18;
+
+/// Whether every visible date gets a column at least `MIN_COLUMN_WIDTH` wide; the main
+/// screen falls back to the single-date list when they don't
+pub fn week_fits(app: &App, area: Rect) -> bool {
+    let columns = app.visible_dates().len() as u16;
+    columns > 0 && area.width / columns >= MIN_COLUMN_WIDTH
+}
+
+/// Renders one column per visible date, each listing its movies with their times. The
+/// selected date's column is outlined and its selected movie highlighted.
+pub fn render_week(frame: &mut Frame, app: &mut App, area: Rect) {
+    app.clamp_date_selection();
+    app.clamp_list_selection();
+    // The date header isn't drawn here, so no clicks should land on where it was
+    app.click_targets = ClickTargets::default();
+    let theme = theme(&app.config);
+    let today = app.config.today();
+
+    let dates: Vec<_> = app.visible_dates().iter().map(|date| date.date_naive()).collect();
+    let columns = Layout::horizontal(vec![Constraint::Fill(1); dates.len()]).split(area);
+
+    for (index, (date, column)) in dates.iter().zip(columns.iter()).enumerate() {
+        let selected = index == app.selected_date_index;
+        let mut title = date.format(app.config.date_format.short()).to_string();
+        if *date == today {
+            title = format!("Today {}", title);
+        }
+        let border_style = if selected {
+            Style::default().fg(theme.accent)
+        } else {
+            Style::default().fg(theme.muted)
+        };
+        let title_style = if selected {
+            Style::default().fg(theme.accent).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.header)
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(Span::styled(title, title_style));
+
+        // Borders take two columns
+        let width = column.width.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = app
+            .filtered_movies_on(*date)
+            .into_iter()
+            .map(|(name, mut times)| {
+                times.sort();
+                let times = times
+                    .iter()
+                    .map(|time| format_showtime(time, app.config.use_24h))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                ListItem::new(vec![
+                    Line::from(Span::styled(
+                        truncate_to_width(&name, width),
+                        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+                    )),
+                    Line::from(Span::styled(
+                        truncate_to_width(&format!(" {}", times), width),
+                        Style::default().fg(theme.muted),
+                    )),
+                ])
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(theme.highlight());
+        // Only the selected column shows a selection, scrolled into view
+        let mut state = ListState::default()
+            .with_selected(selected.then_some(app.selected_movie_index));
+        frame.render_stateful_widget(list, *column, &mut state);
+    }
+}